@@ -1,6 +1,6 @@
 import sys
 import types
-from collections.abc import Callable, Generator, Iterator, Sequence
+from collections.abc import Callable, Generator, Iterable, Iterator, Sequence
 from io import BufferedRandom, BufferedReader, BufferedWriter, FileIO, TextIOWrapper
 from os import PathLike, stat_result
 from pathlib.types import PathInfo
@@ -38,9 +38,14 @@ class PurePath(PathLike[str]):
     in sets. Paths of the same flavor are comparable and orderable.
 
     Args:
-        *pathsegments: Path components to combine. Each element can be a `str` or an object implementing `os.PathLike`.
+        *pathsegments: Path components to combine. Each element can be a `str`, `bytes` (decoded with `os.fsdecode`) or an object implementing `os.PathLike`.
         If empty, the current directory ('.') is assumed.
 
+    Raises:
+        ValueError: If a segment contains a NUL character.
+        UnicodeEncodeError: If a segment holds lone surrogates, as `os.fsdecode`
+            produces for bytes that aren't valid in the filesystem encoding.
+
     Examples:
     ```python
     >>> from pyopath import PurePosixPath
@@ -68,8 +73,9 @@ class PurePath(PathLike[str]):
     ) -> bool:
         """Match this path against a glob-style pattern including wildcards.
 
-        This method supports the full recursive wildcard `**`. Unlike `match()`,
-        the pattern is matched against the entire path, not just the suffix.
+        This method supports the full recursive wildcard `**`, which can span
+        any number of components, while `match()` treats it like `*`. Unlike
+        `match()`, the pattern is matched against the entire path, not just the suffix.
 
         Args:
             pattern (str): A glob-style pattern using `*`, `?`, `[seq]`, and `**` wildcards.
@@ -85,6 +91,8 @@ class PurePath(PathLike[str]):
         True
         >>> PurePosixPath('a/b/c.py').full_match('**/*.py')
         True
+        >>> PurePosixPath('a/b/c.py').full_match('a/**/*.py')
+        True
         >>> PurePosixPath('a/b.py').full_match('*.py')
         False
 
@@ -248,8 +256,8 @@ class PurePath(PathLike[str]):
 
         ```
         """
-    def __new__(cls, *args: StrPath, **kwargs: Unused) -> Self: ...
-    def __init__(self, *args: StrPath) -> None:  # pyright: ignore[reportInconsistentConstructor]
+    def __new__(cls, *args: StrPath | bytes, **kwargs: Unused) -> Self: ...
+    def __init__(self, *args: StrPath | bytes) -> None:  # pyright: ignore[reportInconsistentConstructor]
         """Initialize a path instance.
 
         Args:
@@ -272,6 +280,15 @@ class PurePath(PathLike[str]):
         Returns:
             str: The string representation of the path.
         """
+    def __reduce__(self) -> tuple[type[Self], tuple[str, ...]]:
+        """Support pickling by reconstructing from the string form of the path.
+
+        The concrete flavor is stored rather than `PurePath`, so a Windows path
+        pickled on Windows unpickles as a `PureWindowsPath` on any platform.
+
+        Returns:
+            tuple[type[Self], tuple[str, ...]]: The class and its constructor arguments.
+        """
     def __lt__(self, other: PurePath) -> bool:
         """Check if this path is lexicographically less than **other**.
 
@@ -319,6 +336,9 @@ class PurePath(PathLike[str]):
         Returns:
             Self: A new path with the segments combined.
 
+        Raises:
+            TypeError: If **key** is a path of the other flavor.
+
         Examples:
         ```python
         >>> from pyopath import PurePosixPath
@@ -379,12 +399,46 @@ class PurePath(PathLike[str]):
         >>> PurePosixPath('windows').as_posix()
         'windows'
 
+        ```
+        """
+    def to_posix(self) -> PurePosixPath:
+        """Convert the path to a `PurePosixPath`, re-parsing it under POSIX rules.
+
+        Backslashes of a Windows path become forward slashes.
+
+        Returns:
+            PurePosixPath: The converted path.
+
+        Examples:
+        ```python
+        >>> from pyopath import PureWindowsPath
+        >>> PureWindowsPath('C:\\\\Users\\\\file.txt').to_posix()
+        PurePosixPath('C:/Users/file.txt')
+
+        ```
+        """
+    def to_windows(self) -> PureWindowsPath:
+        """Convert the path to a `PureWindowsPath`, re-parsing it under Windows rules.
+
+        Forward slashes of a POSIX path become backslashes.
+
+        Returns:
+            PureWindowsPath: The converted path.
+
+        Examples:
+        ```python
+        >>> from pyopath import PurePosixPath
+        >>> str(PurePosixPath('a/b').to_windows())
+        'a\\\\b'
+
         ```
         """
     def as_uri(self) -> str:
         """Represent the path as a 'file' URI (RFC 8089).
 
         The path must be absolute, otherwise `ValueError` is raised.
+        Characters outside the unreserved URL set are percent-encoded, Windows
+        drives give `file:///C:/...` and UNC shares give `file://server/share/...`.
 
         Returns:
             str: The path represented as a file URI.
@@ -397,6 +451,34 @@ class PurePath(PathLike[str]):
         >>> from pyopath import PurePosixPath
         >>> PurePosixPath('/home').as_uri()
         'file:///home'
+        >>> PurePosixPath('/my docs/a.txt').as_uri()
+        'file:///my%20docs/a.txt'
+
+        ```
+        """
+    @classmethod
+    def from_uri(cls, uri: str) -> Self:
+        """Create a path from a 'file' URI, the inverse of `as_uri()`.
+
+        Percent-escapes are decoded, and the `file:///C:/...` and
+        `file://server/share/...` forms give Windows drive and UNC paths.
+
+        Args:
+            uri (str): A URI starting with `file:`.
+
+        Returns:
+            Self: The absolute path the URI points to.
+
+        Raises:
+            ValueError: If the URI does not start with `file:` or is not absolute.
+            UnicodeEncodeError: If a percent-escape decodes to bytes that aren't valid
+                in the filesystem encoding.
+
+        Examples:
+        ```python
+        >>> from pyopath import PurePosixPath
+        >>> PurePosixPath.from_uri('file:///my%20docs/a.txt')
+        PurePosixPath('/my docs/a.txt')
 
         ```
         """
@@ -417,6 +499,89 @@ class PurePath(PathLike[str]):
         >>> PurePosixPath('a/b').is_absolute()
         False
 
+        ```
+        """
+    def is_empty(self) -> bool:
+        """Check if the path has no anchor and no parts.
+
+        Such a path renders as `'.'`.
+
+        Returns:
+            bool: `True` if the path is empty, `False` otherwise.
+
+        Examples:
+        ```python
+        >>> from pyopath import PurePosixPath
+        >>> PurePosixPath().is_empty()
+        True
+        >>> PurePosixPath('a/..').is_empty()
+        False
+
+        ```
+        """
+    def is_dot(self) -> bool:
+        """Check if the path is the current directory `'.'`.
+
+        Equivalent to `is_empty()`.
+
+        Returns:
+            bool: `True` if the path renders as `'.'`, `False` otherwise.
+
+        Examples:
+        ```python
+        >>> from pyopath import PurePosixPath
+        >>> PurePosixPath('.').is_dot()
+        True
+        >>> PurePosixPath('/').is_dot()
+        False
+
+        ```
+        """
+    def is_reserved(self) -> bool:
+        """Check if the path contains a name reserved by Windows.
+
+        Device names such as `CON`, `NUL`, `COM1` or `LPT9` are reserved even with
+        an extension, as are names ending with a dot or a space and names holding
+        characters Windows forbids. Follows `ntpath.isreserved()`.
+        On POSIX paths this always returns `False`.
+
+        Returns:
+            bool: `True` if any component is reserved, `False` otherwise.
+
+        Examples:
+        ```python
+        >>> from pyopath import PurePosixPath, PureWindowsPath
+        >>> PureWindowsPath('CON').is_reserved()
+        True
+        >>> PureWindowsPath('logs/nul.txt').is_reserved()
+        True
+        >>> PureWindowsPath('console').is_reserved()
+        False
+        >>> PurePosixPath('CON').is_reserved()
+        False
+
+        ```
+        """
+    def normpath(self) -> Self:
+        """Collapse `..` segments lexically, like `os.path.normpath()`.
+
+        Each `..` removes the component before it. Leading `..` are kept in
+        relative paths and dropped at the root of anchored ones. The filesystem
+        is not accessed, so symlinks are not taken into account.
+
+        Returns:
+            Self: The normalized path.
+
+        Examples:
+        ```python
+        >>> from pyopath import PurePosixPath
+        >>> PurePosixPath('a/b/../c').normpath()
+        PurePosixPath('a/c')
+        >>> PurePosixPath('/../x').normpath()
+        PurePosixPath('/x')
+        >>> PurePosixPath('../a/..').normpath()
+        PurePosixPath('..')
+
         ```
         """
     def is_relative_to(self, other: StrPath) -> bool:
@@ -439,11 +604,90 @@ class PurePath(PathLike[str]):
         >>> PurePath('/etc/passwd').is_relative_to('/usr')
         False
 
+        ```
+        """
+    def starts_with(self, prefix: StrPath) -> bool:
+        """Check if the leading components of the path match **prefix**.
+
+        Alias of `is_relative_to()`. Components are compared whole, and
+        case-insensitively on Windows.
+
+        Args:
+            prefix (str | PathLike): The leading components to look for.
+
+        Returns:
+            bool: `True` if the path starts with prefix, `False` otherwise.
+
+        Examples:
+        ```python
+        >>> from pyopath import PurePosixPath
+        >>> PurePosixPath('/a/b/c').starts_with('/a/b')
+        True
+        >>> PurePosixPath('/a/bc').starts_with('/a/b')
+        False
+
+        ```
+        """
+    def ends_with(self, suffix: StrPath) -> bool:
+        """Check if the trailing components of the path match **suffix**.
+
+        Components are compared whole, and case-insensitively on Windows. An
+        anchored suffix only matches when it is equal to the whole path.
+
+        Args:
+            suffix (str | PathLike): The trailing components to look for.
+
+        Returns:
+            bool: `True` if the path ends with suffix, `False` otherwise.
+
+        Examples:
+        ```python
+        >>> from pyopath import PurePosixPath, PureWindowsPath
+        >>> PurePosixPath('/a/b/c').ends_with('b/c')
+        True
+        >>> PurePosixPath('/a/xb/c').ends_with('b/c')
+        False
+        >>> PureWindowsPath('C:/Users/Me').ends_with('users/me')
+        True
+
         ```
         """
     def match(
-        self, path_pattern: str, *, case_sensitive: bool | None = None
-    ) -> bool: ...
+        self, path_pattern: StrPath, *, case_sensitive: bool | None = None
+    ) -> bool:
+        """Match this path against a glob-style pattern, from the right.
+
+        If the pattern is relative, the path can be either relative or absolute,
+        and matching is done from the right. If the pattern is absolute, the whole
+        path must match. Unlike `full_match()`, `**` does not span several
+        components and behaves like `*`.
+
+        Args:
+            path_pattern (str | PathLike): A glob-style pattern using `*`, `?` and `[seq]` wildcards.
+            case_sensitive (bool | None): Override platform's case-sensitivity. If `None`, uses platform defaults.
+
+        Returns:
+            bool: `True` if the path matches the pattern, `False` otherwise.
+
+        Raises:
+            ValueError: If the pattern is empty.
+
+        Examples:
+        ```python
+        >>> from pyopath import PurePosixPath
+        >>> PurePosixPath('a/b.py').match('*.py')
+        True
+        >>> PurePosixPath('/a/b/c.py').match('b/*.py')
+        True
+        >>> PurePosixPath('/a/b/c.py').match('/*.py')
+        False
+        >>> PurePosixPath('a/b/c.py').match('a/**/c.py')
+        True
+        >>> PurePosixPath('a/b/c/d.py').match('a/**/d.py')
+        False
+
+        ```
+        """
     def relative_to(self, other: StrPath, *, walk_up: bool = False) -> Self:
         """Compute a version of this path relative to **other**.
 
@@ -471,6 +715,8 @@ class PurePath(PathLike[str]):
         PurePosixPath('etc/passwd')
         >>> PurePosixPath('/etc/passwd').relative_to('/etc')
         PurePosixPath('passwd')
+        >>> PurePosixPath('/etc/passwd').relative_to('/usr/bin', walk_up=True)
+        PurePosixPath('../../etc/passwd')
 
         ```
         """
@@ -534,6 +780,10 @@ class PurePath(PathLike[str]):
         Returns:
             Self: A new path with the changed suffix.
 
+        Raises:
+            ValueError: If the suffix doesn't start with a dot, is just '.', would put a
+                separator in the name, or the path has an empty name.
+
         Examples:
         ```python
         >>> from pyopath import PurePosixPath
@@ -555,6 +805,10 @@ class PurePath(PathLike[str]):
         Returns:
             Self: A new path with the segments combined.
 
+        Raises:
+            TypeError: If a segment is a path of the other flavor. Convert it first
+                with `to_posix()` or `to_windows()`.
+
         Examples:
         ```python
         >>> from pyopath import PurePosixPath
@@ -571,6 +825,7 @@ class PurePath(PathLike[str]):
 
         Returns a sequence where index 0 is the immediate parent, index 1 is
         the grandparent, etc. You cannot go past the anchor (root) of the path.
+        Negative indices and slices are supported, slices give a tuple.
 
         Returns:
             Sequence[Self]: A sequence of ancestor paths.
@@ -583,6 +838,11 @@ class PurePath(PathLike[str]):
         PurePosixPath('/foo/bar')
         >>> p.parents[1]
         PurePosixPath('/foo')
+        >>> p.parents[-1]
+        PurePosixPath('/')
+        >>> len(p.parents)
+        3
+
         ```
         """
     @property
@@ -606,6 +866,29 @@ class PurePath(PathLike[str]):
         >>> PurePosixPath('/').parent
         PurePosixPath('/')
 
+        ```
+        """
+    def expanduser(self) -> Self:
+        """Return a new path with an expanded `~` or `~user` first component.
+
+        The home directory is looked up with the flavor's `os.path.expanduser()`,
+        so this reads the environment. Paths that don't start with a `~` form are
+        returned unchanged.
+
+        Returns:
+            Self: The path with the home directory substituted.
+
+        Raises:
+            RuntimeError: If the home directory can't be determined.
+
+        Examples:
+        ```python
+        >>> from pyopath import PurePosixPath
+        >>> PurePosixPath('docs/notes.txt').expanduser()
+        PurePosixPath('docs/notes.txt')
+        >>> PurePosixPath('~/notes.txt').expanduser().name
+        'notes.txt'
+
         ```
         """
     def with_segments(self, *args: StrPath) -> Self:
@@ -620,6 +903,90 @@ class PurePath(PathLike[str]):
         Returns:
             Self: A new path of the same type.
         """
+    @classmethod
+    def common_path(cls, paths: Iterable[StrPath]) -> Self:
+        """Return the longest common ancestor of several paths.
+
+        Equivalent to `os.path.commonpath()` for the flavor of the class: only whole
+        components are compared, case-insensitively on Windows. The result keeps the
+        spelling of the first path.
+
+        Args:
+            paths (Iterable[StrPath]): The paths to compare.
+
+        Returns:
+            Self: The common ancestor, or `'.'` for relative paths with nothing in common.
+
+        Raises:
+            ValueError: If `paths` is empty, mixes drives, or mixes absolute and
+                relative paths (rooted and not-rooted ones for driveless Windows paths).
+
+        Examples:
+        ```python
+        >>> from pyopath import PurePosixPath
+        >>> PurePosixPath.common_path(['/usr/lib', '/usr/local'])
+        PurePosixPath('/usr')
+        >>> PurePosixPath.common_path(['a/b', 'a/b/c'])
+        PurePosixPath('a/b')
+
+        ```
+        """
+    @classmethod
+    def common_prefix(cls, paths: Iterable[StrPath]) -> str:
+        """Return the longest common string prefix of several paths.
+
+        Equivalent to `os.path.commonprefix()` applied to `os.fspath()` of each
+        path: strings are compared as given, without normalizing separators or
+        dropping `.` segments. Unlike `common_path()`, the comparison is character
+        by character, so the result may end in the middle of a component and is
+        returned as a `str`.
+
+        Args:
+            paths (Iterable[StrPath]): The paths to compare.
+
+        Returns:
+            str: The common prefix, or an empty string if `paths` is empty.
+
+        Examples:
+        ```python
+        >>> from pyopath import PurePosixPath
+        >>> PurePosixPath.common_prefix(['/usr/lib', '/usr/local'])
+        '/usr/l'
+        >>> PurePosixPath.common_path(['/usr/lib', '/usr/local'])
+        PurePosixPath('/usr')
+
+        ```
+        """
+    @classmethod
+    def from_parts(cls, drive: str, root: str, parts: Sequence[str]) -> Self:
+        """Create a path directly from its drive, root and components.
+
+        The components are used as-is, without parsing a joined string, which is
+        faster and keeps them exactly as given when they are already split.
+
+        Args:
+            drive (str): The drive, or an empty string.
+            root (str): The root, or an empty string.
+            parts (Sequence[str]): The components following the anchor.
+
+        Returns:
+            Self: A new path of the same type.
+
+        Raises:
+            ValueError: If a component is empty, is `'.'` or contains a path separator,
+                if any argument contains a NUL character, or if **drive** and **root**
+                aren't what the flavor splits off `drive + root`.
+
+        Examples:
+        ```python
+        >>> from pyopath import PurePosixPath
+        >>> PurePosixPath.from_parts('', '/', ['usr', 'bin'])
+        PurePosixPath('/usr/bin')
+        >>> PurePosixPath.from_parts('', '', ['a', 'b.txt']).name
+        'b.txt'
+
+        ```
+        """
 
 class PurePosixPath(PurePath):
     __slots__ = ()
@@ -84,6 +84,82 @@ def benchmark_pure_path_creation() -> BenchmarkResult:
     )
 
 
+def benchmark_pure_path_creation_absolute() -> BenchmarkResult:
+    """Benchmark creating and parsing a single long absolute segment."""
+    path = "/very/long/absolute/path/to/some/deeply/nested/file.txt"
+    return _compare(
+        "PurePath creation (single absolute, parsed)",
+        Category.PURE_PATH,
+        lambda: pyopath.PurePath(path).parts,
+        lambda: pathlib.PurePath(path).parts,
+    )
+
+
+def _benchmark_parse(
+    name: str,
+    path: str,
+    pyopath_cls: type[pyopath.PurePosixPath | pyopath.PureWindowsPath],
+    pathlib_cls: type[pathlib.PurePath],
+) -> BenchmarkResult:
+    """Benchmark constructing a path and forcing it to be parsed."""
+    return _compare(
+        name,
+        Category.PURE_PATH,
+        lambda: pyopath_cls(path).parts,
+        lambda: pathlib_cls(path).parts,
+    )
+
+
+def benchmark_parse_simple_name() -> BenchmarkResult:
+    """Benchmark parsing a single file name."""
+    return _benchmark_parse(
+        "parse simple name",
+        "file.txt",
+        pyopath.PurePosixPath,
+        pathlib.PurePosixPath,
+    )
+
+
+def benchmark_parse_deep_posix() -> BenchmarkResult:
+    """Benchmark parsing a deeply nested POSIX path."""
+    return _benchmark_parse(
+        "parse deep POSIX path",
+        "/" + "/".join(f"dir{i}" for i in range(30)) + "/file.txt",
+        pyopath.PurePosixPath,
+        pathlib.PurePosixPath,
+    )
+
+
+def benchmark_parse_windows_drive() -> BenchmarkResult:
+    """Benchmark parsing a Windows drive path."""
+    return _benchmark_parse(
+        "parse Windows drive path",
+        "C:\\Users\\user\\Documents\\project\\src\\main.py",
+        pyopath.PureWindowsPath,
+        pathlib.PureWindowsPath,
+    )
+
+
+def benchmark_parse_unc() -> BenchmarkResult:
+    """Benchmark parsing a Windows UNC path."""
+    return _benchmark_parse(
+        "parse UNC path",
+        "\\\\server\\share\\projects\\src\\main.py",
+        pyopath.PureWindowsPath,
+        pathlib.PureWindowsPath,
+    )
+
+
+def benchmark_parse_windows_mixed() -> BenchmarkResult:
+    """Benchmark parsing a Windows path mixing both separators."""
+    return _benchmark_parse(
+        "parse Windows mixed separators",
+        "C:/Users\\user/Documents\\project/src\\main.py",
+        pyopath.PureWindowsPath,
+        pathlib.PureWindowsPath,
+    )
+
+
 def benchmark_path_parts() -> BenchmarkResult:
     """Benchmark accessing path parts."""
     pyopath_p = pyopath.PurePath("/home/user/documents/project/src/main.py")
@@ -244,6 +320,12 @@ def _collect_all_benchmarks() -> pc.Vec[BenchmarkResult]:
     pc.Iter(
         (
             benchmark_pure_path_creation(),
+            benchmark_pure_path_creation_absolute(),
+            benchmark_parse_simple_name(),
+            benchmark_parse_deep_posix(),
+            benchmark_parse_windows_drive(),
+            benchmark_parse_windows_mixed(),
+            benchmark_parse_unc(),
             benchmark_path_parts(),
             benchmark_joinpath(),
             benchmark_joinpath_path(),
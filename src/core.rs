@@ -19,6 +19,31 @@ impl ParsedParts {
         result
     }
 
+    /// No drive, no root and no parts: the path renders as "."
+    pub fn is_empty(&self) -> bool {
+        self.drive.is_empty() && self.root.is_empty() && self.parts.is_empty()
+    }
+
+    /// Collapse ".." against the preceding parts, like os.path.normpath
+    /// Leading ".." are kept in relative paths and dropped at the root
+    pub fn normalized(&self) -> ParsedParts {
+        let mut parts: Vec<String> = Vec::with_capacity(self.parts.len());
+        for part in &self.parts {
+            if part != ".." {
+                parts.push(part.clone());
+            } else if parts.last().is_some_and(|last| last != "..") {
+                parts.pop();
+            } else if self.root.is_empty() {
+                parts.push(part.clone());
+            }
+        }
+        ParsedParts {
+            drive: self.drive.clone(),
+            root: self.root.clone(),
+            parts,
+        }
+    }
+
     pub fn name(&self) -> String {
         self.parts.last().cloned().unwrap_or_default()
     }
@@ -31,64 +56,41 @@ impl ParsedParts {
         }
     }
 
+    /// Position of the suffix dot, if the name has a suffix
+    /// The dot must be neither the first nor the last character,
+    /// so "..." or "a." have no suffix while "..a" does
+    fn suffix_dot(name: &str) -> Option<usize> {
+        name.rfind('.')
+            .filter(|&idx| idx > 0 && idx < name.len() - 1)
+    }
+
     pub fn stem(&self) -> String {
         let name = self.name();
-        // Special case: "." and ".." should return themselves
-        if name == "." || name == ".." {
-            return name;
-        }
-        // Find the LAST dot, but not if it's at the start of the name
-        if let Some(idx) = name.rfind('.') {
-            if idx == 0 {
-                name
-            } else {
-                name[..idx].to_string()
-            }
-        } else {
-            name
+        match Self::suffix_dot(&name) {
+            Some(idx) => name[..idx].to_string(),
+            None => name,
         }
     }
 
     pub fn suffix(&self) -> String {
         let name = self.name();
-        // Special case: "." and ".." have no suffix
-        if name == "." || name == ".." {
-            return String::new();
-        }
-        // Find the LAST dot, but not if it's at the start of the name
-        if let Some(idx) = name.rfind('.') {
-            if idx == 0 {
-                String::new()
-            } else {
-                name[idx..].to_string()
-            }
-        } else {
-            String::new()
+        match Self::suffix_dot(&name) {
+            Some(idx) => name[idx..].to_string(),
+            None => String::new(),
         }
     }
 
     pub fn suffixes(&self) -> Vec<String> {
         let name = self.name();
-        // Special case: "." and ".." have no suffixes
-        if name == "." || name == ".." {
+        // A trailing dot means no suffixes at all
+        if name.ends_with('.') {
             return Vec::new();
         }
-
-        let mut result = Vec::new();
-        // Find first dot - if it's at position 0, no suffixes
-        if let Some(first_dot) = name.find('.') {
-            if first_dot == 0 {
-                // File starts with dot like ".gitignore" - no suffixes
-                return result;
-            }
-            // For each part after the first split (which is the first dot itself),
-            // add ".part" as a suffix
-            for part in &name[first_dot..].split('.').collect::<Vec<&str>>()[1..] {
-                if !part.is_empty() {
-                    result.push(format!(".{}", part));
-                }
-            }
-        }
-        result
+        // Leading dots belong to the stem (".bashrc", "..a")
+        name.trim_start_matches('.')
+            .split('.')
+            .skip(1)
+            .map(|part| format!(".{}", part))
+            .collect()
     }
 }
@@ -0,0 +1,224 @@
+/// A single step of a compiled glob pattern
+#[derive(Clone, Debug)]
+enum Token {
+    /// Exact character
+    Literal(char),
+    /// `?`: exactly one character that is not a separator
+    AnyChar,
+    /// `*`: zero or more characters that are not separators
+    Star,
+    /// `[seq]` / `[!seq]`: one character in (or not in) the given ranges
+    Class {
+        negated: bool,
+        ranges: Vec<(char, char)>,
+    },
+    /// A path separator
+    Sep,
+    /// `**` followed by more components: zero or more whole segments
+    AnySegments,
+    /// `**` as the last component: anything, separators included
+    AnyRest,
+}
+
+/// A glob pattern compiled to tokens
+/// Equivalent to Python's glob.translate(pat, include_hidden=True, seps=sep)
+#[derive(Clone, Debug)]
+pub struct GlobPattern {
+    tokens: Vec<Token>,
+    sep: char,
+    case_sensitive: bool,
+}
+
+impl GlobPattern {
+    /// Compile `pattern` split on `sep`
+    /// `**` only spans several components when `recursive` is true,
+    /// otherwise it behaves like `*`
+    pub fn new(pattern: &str, sep: char, recursive: bool, case_sensitive: bool) -> Self {
+        let mut tokens = Vec::new();
+        let parts: Vec<&str> = pattern.split(sep).collect();
+        let last_idx = parts.len() - 1;
+
+        for (idx, part) in parts.iter().enumerate() {
+            if *part == "*" {
+                // A lone "*" component must match at least one character
+                tokens.push(Token::AnyChar);
+                tokens.push(Token::Star);
+                if idx < last_idx {
+                    tokens.push(Token::Sep);
+                }
+            } else if recursive && *part == "**" {
+                if idx < last_idx {
+                    // Consecutive "**" components collapse into one
+                    if parts[idx + 1] != "**" {
+                        tokens.push(Token::AnySegments);
+                    }
+                } else {
+                    tokens.push(Token::AnyRest);
+                }
+            } else {
+                Self::translate_segment(part, &mut tokens);
+                if idx < last_idx {
+                    tokens.push(Token::Sep);
+                }
+            }
+        }
+
+        Self {
+            tokens,
+            sep,
+            case_sensitive,
+        }
+    }
+
+    /// Translate a single component, following fnmatch's rules
+    fn translate_segment(segment: &str, tokens: &mut Vec<Token>) {
+        let chars: Vec<char> = segment.chars().collect();
+        let mut i = 0;
+        while i < chars.len() {
+            match chars[i] {
+                '*' => {
+                    // Compress consecutive "*" into one
+                    if !matches!(tokens.last(), Some(Token::Star)) {
+                        tokens.push(Token::Star);
+                    }
+                    i += 1;
+                }
+                '?' => {
+                    tokens.push(Token::AnyChar);
+                    i += 1;
+                }
+                '[' => match Self::parse_class(&chars, i + 1) {
+                    Some((token, next)) => {
+                        tokens.push(token);
+                        i = next;
+                    }
+                    None => {
+                        // Unterminated class: "[" is a literal
+                        tokens.push(Token::Literal('['));
+                        i += 1;
+                    }
+                },
+                c => {
+                    tokens.push(Token::Literal(c));
+                    i += 1;
+                }
+            }
+        }
+    }
+
+    /// Parse a bracket expression whose content starts at `start`
+    /// Returns the class token and the index after the closing "]"
+    fn parse_class(chars: &[char], start: usize) -> Option<(Token, usize)> {
+        let mut j = start;
+        let negated = chars.get(j) == Some(&'!');
+        if negated {
+            j += 1;
+        }
+        // A "]" right after the opening bracket is a member, not the end
+        if chars.get(j) == Some(&']') {
+            j += 1;
+        }
+        while j < chars.len() && chars[j] != ']' {
+            j += 1;
+        }
+        if j >= chars.len() {
+            return None;
+        }
+
+        let content_start = if negated { start + 1 } else { start };
+        let content = &chars[content_start..j];
+        let mut ranges = Vec::new();
+        let mut k = 0;
+        while k < content.len() {
+            if k + 2 < content.len() && content[k + 1] == '-' {
+                let (lo, hi) = (content[k], content[k + 2]);
+                // Reversed ranges are empty and never match
+                if lo <= hi {
+                    ranges.push((lo, hi));
+                }
+                k += 3;
+            } else {
+                ranges.push((content[k], content[k]));
+                k += 1;
+            }
+        }
+
+        Some((Token::Class { negated, ranges }, j + 1))
+    }
+
+    /// Whether the pattern matches the whole of `path`
+    pub fn matches(&self, path: &str) -> bool {
+        let chars: Vec<char> = path.chars().collect();
+        self.match_from(0, &chars, 0)
+    }
+
+    fn match_from(&self, t_idx: usize, chars: &[char], c_idx: usize) -> bool {
+        let Some(token) = self.tokens.get(t_idx) else {
+            return c_idx == chars.len();
+        };
+
+        match token {
+            Token::Star => {
+                let mut end = c_idx;
+                loop {
+                    if self.match_from(t_idx + 1, chars, end) {
+                        return true;
+                    }
+                    if end >= chars.len() || chars[end] == self.sep {
+                        return false;
+                    }
+                    end += 1;
+                }
+            }
+            Token::AnySegments => {
+                // Either nothing, or at least one character up to a separator
+                if self.match_from(t_idx + 1, chars, c_idx) {
+                    return true;
+                }
+                ((c_idx + 1)..chars.len())
+                    .filter(|&end| chars[end] == self.sep)
+                    .any(|end| self.match_from(t_idx + 1, chars, end + 1))
+            }
+            Token::AnyRest => {
+                (c_idx..=chars.len()).any(|end| self.match_from(t_idx + 1, chars, end))
+            }
+            _ => match chars.get(c_idx) {
+                Some(&c) if self.char_matches(token, c) => {
+                    self.match_from(t_idx + 1, chars, c_idx + 1)
+                }
+                _ => false,
+            },
+        }
+    }
+
+    fn char_matches(&self, token: &Token, c: char) -> bool {
+        match token {
+            Token::Literal(expected) => self.chars_eq(*expected, c),
+            Token::AnyChar => c != self.sep,
+            Token::Sep => c == self.sep,
+            Token::Class { negated, ranges } => {
+                let in_class = ranges.iter().any(|&(lo, hi)| self.in_range(lo, hi, c));
+                in_class != *negated
+            }
+            Token::Star | Token::AnySegments | Token::AnyRest => false,
+        }
+    }
+
+    fn chars_eq(&self, a: char, b: char) -> bool {
+        if self.case_sensitive {
+            a == b
+        } else {
+            a == b || a.to_lowercase().eq(b.to_lowercase())
+        }
+    }
+
+    fn in_range(&self, lo: char, hi: char, c: char) -> bool {
+        if (lo..=hi).contains(&c) {
+            return true;
+        }
+        !self.case_sensitive
+            && c.to_lowercase()
+                .chain(c.to_uppercase())
+                .any(|variant| (lo..=hi).contains(&variant))
+    }
+}
@@ -1,7 +1,9 @@
 use pyo3::prelude::*;
 mod core;
+mod glob;
 mod macros;
 mod separators;
+mod uri;
 use macros::{PurePosixPath, PureWindowsPath};
 // Platform-specific default
 #[cfg(windows)]
@@ -1,63 +1,53 @@
 use crate::core::ParsedParts;
+use crate::glob::GlobPattern;
 use crate::separators::{PosixSeparator, WindowsSeparator};
+use crate::uri;
 use pyo3::prelude::*;
-use pyo3::types::{PyList, PyTuple};
+use pyo3::types::{PyBytes, PyIterator, PyList, PySlice, PyString, PyTuple, PyType};
 use std::sync::OnceLock;
 macro_rules! create_pure_path_class {
-    ($class_name:ident, $separator:ty, $py_name:expr) => {
-        #[pyclass(frozen, name = $py_name)]
+    ($class_name:ident, $parents_name:ident, $separator:ty, $py_name:expr) => {
+        #[pyclass(frozen, module = "pyopath", name = $py_name)]
         pub struct $class_name {
             _raw_path_tuple: Vec<String>,
             str_repr_cached: OnceLock<String>,
-            str_repr_original_cached: OnceLock<String>,
             parsed: OnceLock<ParsedParts>,
             _str_normcase_cached: OnceLock<String>,
             _parts_normcase_cached: OnceLock<Vec<String>>,
         }
 
         impl $class_name {
-            fn compute_str_repr(py: Python, path_strs: &[String]) -> PyResult<(String, String)> {
-                if path_strs.is_empty() {
-                    return Ok((".".to_string(), ".".to_string()));
+            /// Join the raw segments with the flavor's os.path.join
+            /// Separators are normalized later by the flavor's parse
+            fn compute_str_repr(py: Python, path_strs: &[String]) -> PyResult<String> {
+                match path_strs {
+                    [] => Ok(".".to_string()),
+                    // A single segment joins to itself, no need to call os.path.join
+                    [single] => Ok(single.clone()),
+                    _ => PyModule::import(py, <$separator>::MODULE_NAME)?
+                        .getattr("join")?
+                        .call1(PyTuple::new(py, path_strs)?)?
+                        .extract(),
                 }
-
-                // Join all paths (os.path.join will handle it)
-                let path_tuple = PyTuple::new(py, path_strs)?;
-                let joined_str: String = PyModule::import(py, <$separator>::MODULE_NAME)?
-                    .getattr("join")?
-                    .call(path_tuple, None)?
-                    .extract()?;
-
-                // Normalize path separators for the platform
-                let normalized = <$separator>::normalize_path(&joined_str);
-                Ok((joined_str, normalized))
             }
 
+            /// Canonical string form, rebuilt from the parsed parts so that
+            /// redundant, trailing and "." segments are dropped like pathlib does
             fn str_repr(&self) -> &String {
-                self.str_repr_cached.get_or_init(|| {
-                    Python::attach(|py| {
-                        Self::compute_str_repr(py, &self._raw_path_tuple)
-                            .map(|(_, normalized)| normalized)
-                            .unwrap_or_else(|_| ".".to_string())
-                    })
-                })
+                self.str_repr_cached
+                    .get_or_init(|| <$separator>::format_parsed_parts(self.parsed_parts()))
             }
 
-            fn str_repr_original(&self) -> &String {
-                self.str_repr_original_cached.get_or_init(|| {
-                    Python::attach(|py| {
+            fn parsed_parts(&self) -> &ParsedParts {
+                self.parsed.get_or_init(|| {
+                    let joined = Python::attach(|py| {
                         Self::compute_str_repr(py, &self._raw_path_tuple)
-                            .map(|(original, _)| original)
                             .unwrap_or_else(|_| ".".to_string())
-                    })
+                    });
+                    <$separator>::parse(&joined)
                 })
             }
 
-            fn parsed_parts(&self) -> &ParsedParts {
-                self.parsed
-                    .get_or_init(|| <$separator>::parse(self.str_repr()))
-            }
-
             fn str_normcase(&self) -> &String {
                 self._str_normcase_cached
                     .get_or_init(|| <$separator>::normalize_case(self.str_repr()))
@@ -80,10 +70,38 @@ macro_rules! create_pure_path_class {
                 items
                     .iter()
                     .map(|item| {
-                        let path_str: String = PyModule::import(py, "os")?
-                            .getattr("fspath")?
-                            .call1((&item,))?
-                            .extract()?;
+                        let os = PyModule::import(py, "os")?;
+                        // Bytes segments are decoded with the filesystem encoding, undecodable
+                        // bytes become lone surrogates that are rejected below
+                        let fspath = if item.is_instance_of::<PyBytes>() {
+                            os.getattr("fsdecode")?.call1((&item,))
+                        } else {
+                            os.getattr("fspath")?.call1((&item,))
+                        };
+                        let path = match fspath {
+                            Ok(path) => path,
+                            Err(err) if err.is_instance_of::<pyo3::exceptions::PyTypeError>(py) => {
+                                item.clone()
+                            }
+                            Err(err) => return Err(err),
+                        };
+                        // __fspath__ may still return bytes, which paths don't accept
+                        if !path.is_instance_of::<PyString>() {
+                            return Err(pyo3::exceptions::PyTypeError::new_err(format!(
+                                "argument should be a str or an os.PathLike object \
+                                 where __fspath__ returns a str, not {}",
+                                path.get_type().name()?.repr()?
+                            )));
+                        }
+                        // A str that can't be encoded, such as one with lone surrogates,
+                        // keeps its UnicodeEncodeError
+                        let path_str: String = path.extract()?;
+                        // A NUL byte would silently truncate the path once it reaches a syscall
+                        if path_str.contains('\0') {
+                            return Err(pyo3::exceptions::PyValueError::new_err(
+                                "embedded null byte",
+                            ));
+                        }
 
                         // If current separator is different from source, convert
                         let converted = if <$separator>::MODULE_NAME == "posixpath" {
@@ -106,19 +124,70 @@ macro_rules! create_pure_path_class {
                     })
                     .collect()
             }
+            /// Refuse to join a path of the other flavor, which would be reparsed under ours
+            fn check_same_flavor(item: &Bound<PyAny>) -> PyResult<()> {
+                let is_path = item.is_instance_of::<PurePosixPath>()
+                    || item.is_instance_of::<PureWindowsPath>();
+                if !is_path || item.is_instance_of::<Self>() {
+                    return Ok(());
+                }
+                let conversion = if <$separator>::MODULE_NAME == "posixpath" {
+                    "to_posix"
+                } else {
+                    "to_windows"
+                };
+                Err(pyo3::exceptions::PyTypeError::new_err(format!(
+                    "cannot join {} to {}; convert it with {}() first",
+                    item.get_type().name()?,
+                    $py_name,
+                    conversion
+                )))
+            }
+
+            /// Convert the other operand of `/` to a path string
+            /// Returns None for unsupported types so Python can try the reflected operation
+            fn extract_operand(py: Python, key: &Bound<PyAny>) -> PyResult<Option<String>> {
+                if let Ok(other) = key.cast::<Self>() {
+                    return Ok(Some(other.get().str_repr().clone()));
+                }
+                Self::check_same_flavor(key)?;
+                match Self::extract_path_strs(py, &PyTuple::new(py, [key])?) {
+                    Ok(mut strs) => Ok(strs.pop()),
+                    Err(err) if err.is_instance_of::<pyo3::exceptions::PyTypeError>(py) => Ok(None),
+                    Err(err) => Err(err),
+                }
+            }
+
+            /// Parse a path or path-like argument such as the base of relative_to
+            fn parse_other(py: Python, other: &Bound<PyAny>) -> PyResult<ParsedParts> {
+                if let Ok(other_path) = other.cast::<Self>() {
+                    return Ok(other_path.get().parsed_parts().clone());
+                }
+                let other_str = Self::extract_path_strs(py, &PyTuple::new(py, [other])?)?
+                    .pop()
+                    .unwrap_or_default();
+                Ok(<$separator>::parse(&other_str))
+            }
+
+            /// Compare components the way the flavor compares paths
+            fn parts_eq(left: &[String], right: &[String]) -> bool {
+                left.len() == right.len()
+                    && left.iter().zip(right).all(|(l, r)| {
+                        l == r || <$separator>::normalize_case(l) == <$separator>::normalize_case(r)
+                    })
+            }
+
             /// Create a path from already-parsed parts
             fn from_parsed_parts(parsed: ParsedParts) -> Self {
                 let str_repr = <$separator>::format_parsed_parts(&parsed);
                 let path = Self {
                     _raw_path_tuple: vec![],
                     str_repr_cached: OnceLock::new(),
-                    str_repr_original_cached: OnceLock::new(),
                     parsed: OnceLock::new(),
                     _str_normcase_cached: OnceLock::new(),
                     _parts_normcase_cached: OnceLock::new(),
                 };
-                let _ = path.str_repr_cached.set(str_repr.clone());
-                let _ = path.str_repr_original_cached.set(str_repr);
+                let _ = path.str_repr_cached.set(str_repr);
                 let _ = path.parsed.set(parsed);
                 path
             }
@@ -133,23 +202,158 @@ macro_rules! create_pure_path_class {
                 Ok(Self {
                     _raw_path_tuple: path_strs,
                     str_repr_cached: OnceLock::new(),
-                    str_repr_original_cached: OnceLock::new(),
                     parsed: OnceLock::new(),
                     _str_normcase_cached: OnceLock::new(),
                     _parts_normcase_cached: OnceLock::new(),
                 })
             }
 
+            /// Build a path from already-split components, skipping string parsing
+            #[staticmethod]
+            #[pyo3(signature = (drive, root, parts))]
+            fn from_parts(
+                py: Python,
+                drive: String,
+                root: String,
+                parts: Vec<String>,
+            ) -> PyResult<Py<Self>> {
+                if std::iter::once(&drive)
+                    .chain(std::iter::once(&root))
+                    .chain(&parts)
+                    .any(|s| s.contains('\0'))
+                {
+                    return Err(pyo3::exceptions::PyValueError::new_err(
+                        "embedded null byte",
+                    ));
+                }
+                if let Some(part) = parts.iter().find(|p| <$separator>::contains_separator(p)) {
+                    return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                        "Invalid part {}: parts must not contain separators",
+                        part.into_pyobject(py)?.repr()?
+                    )));
+                }
+                // Parsing never yields empty or "." parts, so paths holding them
+                // would not compare equal to the same path parsed from a string
+                if let Some(part) = parts.iter().find(|p| p.is_empty() || *p == ".") {
+                    return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                        "Invalid part {}: parts must not be empty or '.'",
+                        part.into_pyobject(py)?.repr()?
+                    )));
+                }
+                let anchor = format!("{}{}", drive, root);
+                if <$separator>::splitroot(&anchor) != (drive.clone(), root.clone(), String::new())
+                {
+                    return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                        "Invalid anchor: drive {} and root {} don't split back from {}",
+                        drive.as_str().into_pyobject(py)?.repr()?,
+                        root.as_str().into_pyobject(py)?.repr()?,
+                        anchor.into_pyobject(py)?.repr()?
+                    )));
+                }
+                Py::new(
+                    py,
+                    Self::from_parsed_parts(ParsedParts { drive, root, parts }),
+                )
+            }
+
+            /// Longest common ancestor of several paths, like os.path.commonpath
+            #[staticmethod]
+            fn common_path(py: Python, paths: &Bound<PyAny>) -> PyResult<Py<Self>> {
+                let items: Vec<Bound<PyAny>> = paths.try_iter()?.collect::<PyResult<_>>()?;
+                let path_strs = Self::extract_path_strs(py, &PyTuple::new(py, items)?)?;
+                let parsed: Vec<ParsedParts> =
+                    path_strs.iter().map(|s| <$separator>::parse(s)).collect();
+                let Some((first, rest)) = parsed.split_first() else {
+                    // posixpath and ntpath word this error differently
+                    let message = if <$separator>::MODULE_NAME == "posixpath" {
+                        "commonpath() arg is an empty sequence"
+                    } else {
+                        "commonpath() arg is an empty iterable"
+                    };
+                    return Err(pyo3::exceptions::PyValueError::new_err(message));
+                };
+
+                // The drive is checked first, as ntpath.commonpath does
+                let drive = <$separator>::normalize_case(&first.drive);
+                if rest
+                    .iter()
+                    .any(|p| <$separator>::normalize_case(&p.drive) != drive)
+                {
+                    return Err(pyo3::exceptions::PyValueError::new_err(
+                        "Paths don't have the same drive",
+                    ));
+                }
+                if rest
+                    .iter()
+                    .any(|p| p.root.is_empty() != first.root.is_empty())
+                {
+                    // ntpath calls driveless paths with a root "rooted" rather than absolute
+                    let message = if <$separator>::MODULE_NAME == "ntpath" && first.drive.is_empty()
+                    {
+                        "Can't mix rooted and not-rooted paths"
+                    } else {
+                        "Can't mix absolute and relative paths"
+                    };
+                    return Err(pyo3::exceptions::PyValueError::new_err(message));
+                }
+
+                // Components compare case-insensitively on Windows,
+                // but the result keeps the spelling of the first path
+                let common_len = rest.iter().fold(first.parts.len(), |len, p| {
+                    first.parts[..len]
+                        .iter()
+                        .zip(&p.parts)
+                        .take_while(|(a, b)| {
+                            <$separator>::normalize_case(a) == <$separator>::normalize_case(b)
+                        })
+                        .count()
+                });
+                // commonpath always uses a single separator as root, so POSIX "//" collapses
+                let root = if first.root.is_empty() {
+                    String::new()
+                } else {
+                    <$separator>::SEP.to_string()
+                };
+                let common = ParsedParts {
+                    drive: first.drive.clone(),
+                    root,
+                    parts: first.parts[..common_len].to_vec(),
+                };
+                Py::new(py, Self::from_parsed_parts(common))
+            }
+
+            /// Longest common string prefix, like os.path.commonprefix
+            /// Unlike common_path, the strings aren't normalized and the result
+            /// may end in the middle of a component
+            #[staticmethod]
+            fn common_prefix(py: Python, paths: &Bound<PyAny>) -> PyResult<String> {
+                let items: Vec<Bound<PyAny>> = paths.try_iter()?.collect::<PyResult<_>>()?;
+                let path_strs = Self::extract_path_strs(py, &PyTuple::new(py, items)?)?;
+                let Some((first, rest)) = path_strs.split_first() else {
+                    return Ok(String::new());
+                };
+
+                let common_len = rest.iter().fold(first.len(), |len, other| {
+                    first[..len]
+                        .char_indices()
+                        .zip(other.chars())
+                        .find(|((_, a), b)| a != b)
+                        .map_or(len.min(other.len()), |((idx, _), _)| idx)
+                });
+                Ok(first[..common_len].to_string())
+            }
+
             fn __str__(&self) -> String {
                 self.str_repr().clone()
             }
 
-            fn __repr__(&self) -> String {
-                format!(
-                    "{}('{}')",
+            /// Built from the normalized string with forward slashes, like pathlib
+            fn __repr__(&self, py: Python) -> PyResult<String> {
+                Ok(format!(
+                    "{}({})",
                     stringify!($class_name),
-                    self.str_repr_original()
-                )
+                    self.as_posix().into_pyobject(py)?.repr()?
+                ))
             }
 
             fn __eq__(&self, other: &Bound<PyAny>) -> PyResult<bool> {
@@ -165,20 +369,28 @@ macro_rules! create_pure_path_class {
                 use std::collections::hash_map::DefaultHasher;
                 use std::hash::{Hash, Hasher};
                 let mut hasher = DefaultHasher::new();
+                // Include the flavor so equal strings of both flavors don't collide
+                <$separator>::MODULE_NAME.hash(&mut hasher);
                 self.str_normcase().hash(&mut hasher);
                 hasher.finish()
             }
 
-            fn __truediv__(&self, py: Python, key: String) -> PyResult<Py<Self>> {
+            fn __truediv__(&self, py: Python, key: &Bound<PyAny>) -> PyResult<Py<PyAny>> {
+                let Some(key) = Self::extract_operand(py, key)? else {
+                    return Ok(py.NotImplemented());
+                };
                 let segments = vec![self.str_repr().clone(), key];
                 let segments_tuple = PyTuple::new(py, &segments)?;
-                self.with_segments(py, &segments_tuple)
+                Ok(self.with_segments(py, &segments_tuple)?.into_any())
             }
 
-            fn __rtruediv__(&self, py: Python, key: String) -> PyResult<Py<Self>> {
+            fn __rtruediv__(&self, py: Python, key: &Bound<PyAny>) -> PyResult<Py<PyAny>> {
+                let Some(key) = Self::extract_operand(py, key)? else {
+                    return Ok(py.NotImplemented());
+                };
                 let segments = vec![key, self.str_repr().clone()];
                 let segments_tuple = PyTuple::new(py, &segments)?;
-                self.with_segments(py, &segments_tuple)
+                Ok(self.with_segments(py, &segments_tuple)?.into_any())
             }
 
             #[getter]
@@ -252,13 +464,44 @@ macro_rules! create_pure_path_class {
             }
 
             fn as_posix(&self) -> String {
-                self.str_repr().replace('\\', "/")
+                self.str_repr().replace(<$separator>::SEP, "/")
+            }
+
+            /// Re-parse the path under POSIX rules, separators becoming "/"
+            fn to_posix(slf: &Bound<Self>, py: Python) -> PyResult<Py<PurePosixPath>> {
+                Py::new(py, PurePosixPath::new(py, &PyTuple::new(py, [slf])?)?)
+            }
+
+            /// Re-parse the path under Windows rules, separators becoming "\\"
+            fn to_windows(slf: &Bound<Self>, py: Python) -> PyResult<Py<PureWindowsPath>> {
+                Py::new(py, PureWindowsPath::new(py, &PyTuple::new(py, [slf])?)?)
             }
 
             fn is_absolute(&self) -> bool {
                 <$separator>::is_absolute(self.parsed_parts())
             }
 
+            fn is_empty(&self) -> bool {
+                self.parsed_parts().is_empty()
+            }
+
+            fn is_dot(&self) -> bool {
+                self.is_empty()
+            }
+
+            /// Whether the path names a reserved Windows device, always false on Posix
+            fn is_reserved(&self) -> bool {
+                <$separator>::is_reserved(self.parsed_parts())
+            }
+
+            /// Lexically collapse ".." segments without touching the filesystem
+            fn normpath(&self, py: Python) -> PyResult<Py<Self>> {
+                Py::new(
+                    py,
+                    Self::from_parsed_parts(self.parsed_parts().normalized()),
+                )
+            }
+
             #[pyo3(signature = (*pathsegments))]
             fn with_segments(
                 &self,
@@ -271,6 +514,9 @@ macro_rules! create_pure_path_class {
             #[pyo3(signature = (*paths))]
             fn joinpath(&self, py: Python, paths: &Bound<PyTuple>) -> PyResult<Py<Self>> {
                 // with_segments(self, *paths)
+                for path in paths.iter() {
+                    Self::check_same_flavor(&path)?;
+                }
                 let mut segments = vec![self.str_repr().clone()];
                 segments.extend(Self::extract_path_strs(py, paths)?);
 
@@ -279,104 +525,107 @@ macro_rules! create_pure_path_class {
             }
 
             #[getter]
-            fn parents<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyList>> {
-                let parsed = self.parsed_parts();
-
-                // Build all parent paths
-                let mut parent_objs: Vec<Py<Self>> = Vec::new();
-                let mut current_parts = parsed.parts.clone();
-
-                loop {
-                    if current_parts.is_empty() {
-                        break;
-                    }
-                    current_parts.pop();
-
-                    let parent_parsed = ParsedParts {
-                        drive: parsed.drive.clone(),
-                        root: parsed.root.clone(),
-                        parts: current_parts.clone(),
-                    };
-
-                    let parent_py = Py::new(py, Self::from_parsed_parts(parent_parsed))?;
-                    parent_objs.push(parent_py);
+            fn parents(slf: &Bound<Self>) -> $parents_name {
+                $parents_name {
+                    path: slf.clone().unbind(),
                 }
-
-                PyList::new(py, parent_objs)
             }
 
-            fn is_relative_to(&self, other: &Bound<PyAny>) -> PyResult<bool> {
-                match other.extract::<String>() {
-                    Ok(other_str) => {
-                        let other_path = <$separator>::parse(&other_str);
-                        let self_parsed = self.parsed_parts();
-
-                        // Must have same anchor
-                        if self_parsed.drive != other_path.drive
-                            || self_parsed.root != other_path.root
-                        {
-                            return Ok(false);
-                        }
-
-                        // self.parts must start with other.parts
-                        if other_path.parts.len() > self_parsed.parts.len() {
-                            return Ok(false);
-                        }
+            fn is_relative_to(&self, py: Python, other: &Bound<PyAny>) -> PyResult<bool> {
+                let other_path = Self::parse_other(py, other)?;
+                let self_parsed = self.parsed_parts();
+                let same_anchor = <$separator>::normalize_case(&self_parsed.anchor())
+                    == <$separator>::normalize_case(&other_path.anchor());
 
-                        for (i, other_part) in other_path.parts.iter().enumerate() {
-                            if self_parsed.parts[i] != *other_part {
-                                return Ok(false);
-                            }
-                        }
+                // self.parts must start with other.parts
+                Ok(same_anchor
+                    && other_path.parts.len() <= self_parsed.parts.len()
+                    && Self::parts_eq(
+                        &self_parsed.parts[..other_path.parts.len()],
+                        &other_path.parts,
+                    ))
+            }
 
-                        Ok(true)
-                    }
-                    Err(_) => Ok(false),
-                }
+            /// Whether the leading components match `prefix`, alias of is_relative_to
+            fn starts_with(&self, py: Python, prefix: &Bound<PyAny>) -> PyResult<bool> {
+                self.is_relative_to(py, prefix)
             }
 
-            fn relative_to(&self, py: Python, other: &Bound<PyAny>) -> PyResult<Py<Self>> {
-                let other_str = other.extract::<String>()?;
-                let other_path = <$separator>::parse(&other_str);
+            /// Whether the trailing components match `suffix`
+            /// An anchored suffix must match the whole path
+            fn ends_with(&self, py: Python, suffix: &Bound<PyAny>) -> PyResult<bool> {
+                let suffix_path = Self::parse_other(py, suffix)?;
                 let self_parsed = self.parsed_parts();
-
-                // Must have same anchor
-                if self_parsed.drive != other_path.drive || self_parsed.root != other_path.root {
-                    return Err(pyo3::exceptions::PyValueError::new_err(format!(
-                        "{} is not relative to {}",
-                        self.str_repr(),
-                        other_str
-                    )));
-                }
-
-                // self.parts must start with other.parts
-                if other_path.parts.len() > self_parsed.parts.len() {
-                    return Err(pyo3::exceptions::PyValueError::new_err(format!(
-                        "{} is not relative to {}",
-                        self.str_repr(),
-                        other_str
-                    )));
+                let parts = &self_parsed.parts;
+                if !suffix_path.drive.is_empty() || !suffix_path.root.is_empty() {
+                    return Ok(<$separator>::normalize_case(&self_parsed.anchor())
+                        == <$separator>::normalize_case(&suffix_path.anchor())
+                        && Self::parts_eq(parts, &suffix_path.parts));
                 }
+                Ok(suffix_path.parts.len() <= parts.len()
+                    && Self::parts_eq(
+                        &parts[parts.len() - suffix_path.parts.len()..],
+                        &suffix_path.parts,
+                    ))
+            }
 
-                for (i, other_part) in other_path.parts.iter().enumerate() {
-                    if self_parsed.parts[i] != *other_part {
+            #[pyo3(signature = (other, *, walk_up=false))]
+            fn relative_to(
+                &self,
+                py: Python,
+                other: &Bound<PyAny>,
+                walk_up: bool,
+            ) -> PyResult<Py<Self>> {
+                let other_path = Self::parse_other(py, other)?;
+                let self_parsed = self.parsed_parts();
+                let quote =
+                    |s: &str| -> PyResult<String> { Ok(s.into_pyobject(py)?.repr()?.to_string()) };
+
+                // Drive, root and parts compare case-insensitively on Windows
+                let normcase_parts = |parts: &[String]| -> Vec<String> {
+                    parts
+                        .iter()
+                        .map(|p| <$separator>::normalize_case(p))
+                        .collect()
+                };
+                let same_anchor = <$separator>::normalize_case(&self_parsed.anchor())
+                    == <$separator>::normalize_case(&other_path.anchor());
+                let self_parts = normcase_parts(&self_parsed.parts);
+                let other_parts = normcase_parts(&other_path.parts);
+
+                // Walk up from other towards its anchor until it is an ancestor of self
+                for step in 0..=other_parts.len() {
+                    let base = &other_parts[..other_parts.len() - step];
+                    if same_anchor && self_parts.starts_with(base) {
+                        let mut parts = vec!["..".to_string(); step];
+                        parts.extend_from_slice(&self_parsed.parts[base.len()..]);
+                        let relative_parsed = ParsedParts {
+                            drive: String::new(),
+                            root: String::new(),
+                            parts,
+                        };
+                        return Py::new(py, Self::from_parsed_parts(relative_parsed));
+                    }
+                    if !walk_up {
                         return Err(pyo3::exceptions::PyValueError::new_err(format!(
-                            "{} is not relative to {}",
-                            self.str_repr(),
-                            other_str
+                            "{} is not in the subpath of {}",
+                            quote(self.str_repr())?,
+                            quote(&<$separator>::format_parsed_parts(&other_path))?
+                        )));
+                    }
+                    if base.last().is_some_and(|part| part == "..") {
+                        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                            "'..' segment in {} cannot be walked",
+                            quote(&<$separator>::format_parsed_parts(&other_path))?
                         )));
                     }
                 }
 
-                // Build relative path from remaining parts
-                let remaining: Vec<String> = self_parsed.parts[other_path.parts.len()..].to_vec();
-                let relative_parsed = ParsedParts {
-                    drive: String::new(),
-                    root: String::new(),
-                    parts: remaining,
-                };
-
-                Py::new(py, Self::from_parsed_parts(relative_parsed))
+                Err(pyo3::exceptions::PyValueError::new_err(format!(
+                    "{} and {} have different anchors",
+                    quote(self.str_repr())?,
+                    quote(&<$separator>::format_parsed_parts(&other_path))?
+                )))
             }
 
             fn __lt__(&self, other: &Bound<PyAny>) -> PyResult<bool> {
@@ -423,12 +672,43 @@ macro_rules! create_pure_path_class {
                 self.str_repr().clone()
             }
 
+            /// Pickle as the concrete flavor, so `PurePath` keeps its flavor across platforms
+            fn __reduce__<'py>(
+                &self,
+                py: Python<'py>,
+            ) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+                // Derived paths have no raw segments, so pickle the normalized string
+                let args = PyTuple::new(py, [self.str_repr()])?;
+                Ok((py.get_type::<Self>(), args))
+            }
+
             fn with_name(&self, py: Python, name: &str) -> PyResult<Py<Self>> {
                 let new_parsed = <$separator>::with_name(self.parsed_parts(), name);
                 Py::new(py, Self::from_parsed_parts(new_parsed))
             }
 
             fn with_suffix(&self, py: Python, suffix: &str) -> PyResult<Py<Self>> {
+                if self.parsed_parts().stem().is_empty() {
+                    // If the stem is empty, we can't make the suffix non-empty
+                    return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                        "{} has an empty name",
+                        self.__repr__(py)?
+                    )));
+                }
+                if !suffix.is_empty() && (!suffix.starts_with('.') || suffix == ".") {
+                    return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                        "Invalid suffix {}",
+                        suffix.into_pyobject(py)?.repr()?
+                    )));
+                }
+                // The new name must stay a single component, as pathlib's with_name requires
+                let name = format!("{}{}", self.parsed_parts().stem(), suffix);
+                if name.is_empty() || name == "." || <$separator>::contains_separator(&name) {
+                    return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                        "Invalid name {}",
+                        name.into_pyobject(py)?.repr()?
+                    )));
+                }
                 let new_parsed = <$separator>::with_suffix(self.parsed_parts(), suffix);
                 Py::new(py, Self::from_parsed_parts(new_parsed))
             }
@@ -440,6 +720,41 @@ macro_rules! create_pure_path_class {
                 Py::new(py, Self::from_parsed_parts(new_parsed))
             }
 
+            /// Expand a leading "~" or "~user" component to the home directory
+            /// Uses the flavor's os.path.expanduser, so it reads the environment
+            fn expanduser(slf: &Bound<Self>, py: Python) -> PyResult<Py<Self>> {
+                let parsed = slf.get().parsed_parts();
+                let is_tilde = parsed.drive.is_empty()
+                    && parsed.root.is_empty()
+                    && parsed
+                        .parts
+                        .first()
+                        .is_some_and(|part| part.starts_with('~'));
+                if !is_tilde {
+                    return Ok(slf.clone().unbind());
+                }
+
+                let homedir: String = PyModule::import(py, <$separator>::MODULE_NAME)?
+                    .getattr("expanduser")?
+                    .call1((&parsed.parts[0],))?
+                    .extract()?;
+                if homedir.starts_with('~') {
+                    return Err(pyo3::exceptions::PyRuntimeError::new_err(
+                        "Could not determine home directory.",
+                    ));
+                }
+
+                let home = <$separator>::parse(&homedir);
+                let mut parts = home.parts;
+                parts.extend_from_slice(&parsed.parts[1..]);
+                let expanded = ParsedParts {
+                    drive: home.drive,
+                    root: home.root,
+                    parts,
+                };
+                Py::new(py, Self::from_parsed_parts(expanded))
+            }
+
             fn __bytes__(&self, py: Python) -> PyResult<Vec<u8>> {
                 PyModule::import(py, "os")?
                     .getattr("fsencode")?
@@ -450,142 +765,189 @@ macro_rules! create_pure_path_class {
             fn as_uri(&self) -> PyResult<String> {
                 let parsed = self.parsed_parts();
                 // as_uri only works on absolute paths
-                if parsed.drive.is_empty() && parsed.root.is_empty() {
+                if !self.is_absolute() {
                     return Err(pyo3::exceptions::PyValueError::new_err(
-                        "cannot use as_uri with a relative path",
+                        "relative path can't be expressed as a file URI",
                     ));
                 }
 
-                // Convert path to forward slashes for URI
-                let path_uri = self.str_repr().replace('\\', "/");
-
-                // For Windows paths with drive letter: file:///C:/path
-                if !parsed.drive.is_empty() {
-                    Ok(format!("file:///{}", path_uri))
+                let path = self.as_posix();
+                let drive = &parsed.drive;
+                if drive.chars().count() == 2 && drive.ends_with(':') {
+                    // Local drive: file:///C:/path, the drive itself is not quoted
+                    Ok(format!(
+                        "file:///{}{}",
+                        drive,
+                        uri::quote(&path[drive.len()..])
+                    ))
+                } else if !drive.is_empty() {
+                    // UNC share: file://server/share/path
+                    Ok(format!("file:{}", uri::quote(&path)))
                 } else {
-                    // For POSIX paths: file:///path
-                    Ok(format!("file://{}", path_uri))
+                    // POSIX: file:///path
+                    Ok(format!("file://{}", uri::quote(&path)))
                 }
             }
 
-            fn full_match(&self, pattern: &str) -> PyResult<bool> {
-                // Simple globbing implementation
-                self._glob_match(pattern)
+            /// Parse a 'file' URI into an absolute path, the inverse of as_uri
+            #[staticmethod]
+            fn from_uri(py: Python, uri: &str) -> PyResult<Py<Self>> {
+                let quote =
+                    |s: &str| -> PyResult<String> { Ok(s.into_pyobject(py)?.repr()?.to_string()) };
+                let Some(quoted_path) = uri::file_uri_path(uri) else {
+                    return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                        "URI does not start with 'file:': {}",
+                        quote(uri)?
+                    )));
+                };
+
+                let decoded = PyModule::import(py, "os")?
+                    .getattr("fsdecode")?
+                    .call1((PyBytes::new(py, &uri::unquote_to_bytes(&quoted_path)),))?;
+                let path = Self::new(py, &PyTuple::new(py, [decoded])?)?;
+                if !path.is_absolute() {
+                    return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                        "URI is not absolute: {}",
+                        quote(uri)?
+                    )));
+                }
+                Py::new(py, path)
             }
-        }
 
-        impl $class_name {
-            fn _glob_match(&self, pattern: &str) -> PyResult<bool> {
-                // Convert pathlib glob pattern to simple matching
-                // ** matches zero or more directories
-                // * matches zero or more characters within a directory
-                // ? matches exactly one character
-                // [seq] matches characters in sequence
+            #[pyo3(signature = (pattern, *, case_sensitive=None))]
+            fn full_match(
+                &self,
+                py: Python,
+                pattern: &Bound<PyAny>,
+                case_sensitive: Option<bool>,
+            ) -> PyResult<bool> {
+                let pattern = self.pattern_as_path(py, pattern)?;
+                let case_sensitive = case_sensitive.unwrap_or(<$separator>::CASE_SENSITIVE);
 
-                let path_parts: Vec<&str> = self.str_repr().split(['/', '\\'].as_ref()).collect();
-                let pattern_parts: Vec<&str> = pattern.split(['/', '\\'].as_ref()).collect();
+                // The string form of an empty path is "." but empty paths
+                // shouldn't match wildcards, so both sides use "" instead
+                let path_str = if self.parsed_parts().is_empty() {
+                    String::new()
+                } else {
+                    self.str_repr().clone()
+                };
+                let pattern_parts: Vec<String> = pattern.getattr("parts")?.extract()?;
+                let pattern_str: String = if pattern_parts.is_empty() {
+                    String::new()
+                } else {
+                    pattern.str()?.extract()?
+                };
 
-                self._match_recursive(&path_parts, 0, &pattern_parts, 0)
+                let glob = GlobPattern::new(&pattern_str, <$separator>::SEP, true, case_sensitive);
+                Ok(glob.matches(&path_str))
             }
 
-            fn _match_recursive(
+            #[pyo3(signature = (path_pattern, *, case_sensitive=None))]
+            fn r#match(
                 &self,
-                path_parts: &[&str],
-                p_idx: usize,
-                pattern_parts: &[&str],
-                pat_idx: usize,
+                py: Python,
+                path_pattern: &Bound<PyAny>,
+                case_sensitive: Option<bool>,
             ) -> PyResult<bool> {
-                // Base cases
-                if pat_idx >= pattern_parts.len() {
-                    return Ok(p_idx >= path_parts.len());
+                let pattern = self.pattern_as_path(py, path_pattern)?;
+                let case_sensitive = case_sensitive.unwrap_or(<$separator>::CASE_SENSITIVE);
+
+                // Relative patterns match from the right, component by component
+                let path_parts = self.parsed_parts().all_parts();
+                let pattern_parts: Vec<String> = pattern.getattr("parts")?.extract()?;
+                let pattern_anchor: String = pattern.getattr("anchor")?.extract()?;
+                if pattern_parts.is_empty() {
+                    return Err(pyo3::exceptions::PyValueError::new_err("empty pattern"));
                 }
-
-                if pattern_parts[pat_idx] == "**" {
-                    // ** can match zero or more path segments
-                    if pat_idx + 1 >= pattern_parts.len() {
-                        // ** is the last pattern, matches everything
-                        return Ok(true);
-                    }
-
-                    // Try matching zero segments (skip **)
-                    if self._match_recursive(path_parts, p_idx, pattern_parts, pat_idx + 1)? {
-                        return Ok(true);
-                    }
-
-                    // Try matching one or more segments
-                    if p_idx < path_parts.len() {
-                        return self._match_recursive(
-                            path_parts,
-                            p_idx + 1,
-                            pattern_parts,
-                            pat_idx,
-                        );
-                    }
-
+                if path_parts.len() < pattern_parts.len() {
                     return Ok(false);
                 }
-
-                if p_idx >= path_parts.len() {
+                if path_parts.len() > pattern_parts.len() && !pattern_anchor.is_empty() {
                     return Ok(false);
                 }
 
-                // Match current segment
-                if self._segment_matches(path_parts[p_idx], pattern_parts[pat_idx])? {
-                    return self._match_recursive(
-                        path_parts,
-                        p_idx + 1,
-                        pattern_parts,
-                        pat_idx + 1,
-                    );
+                Ok(path_parts.iter().rev().zip(pattern_parts.iter().rev()).all(
+                    |(path_part, pattern_part)| {
+                        GlobPattern::new(pattern_part, <$separator>::SEP, false, case_sensitive)
+                            .matches(path_part)
+                    },
+                ))
+            }
+        }
+
+        impl $class_name {
+            /// Patterns that are not already path objects go through with_segments
+            fn pattern_as_path<'py>(
+                &self,
+                py: Python<'py>,
+                pattern: &Bound<'py, PyAny>,
+            ) -> PyResult<Bound<'py, PyAny>> {
+                if pattern.hasattr("with_segments")? {
+                    return Ok(pattern.clone());
                 }
+                let segments = PyTuple::new(py, [pattern])?;
+                Ok(self.with_segments(py, &segments)?.into_bound(py).into_any())
+            }
+        }
+
+        /// Immutable sequence of the logical ancestors of a path, like pathlib's _PathParents
+        #[pyclass(frozen, sequence, module = "pyopath", name = "PathParents")]
+        pub struct $parents_name {
+            path: Py<$class_name>,
+        }
 
-                Ok(false)
+        impl $parents_name {
+            /// Ancestor at a non-negative index, 0 being the direct parent
+            fn parent_at(&self, py: Python, idx: usize) -> PyResult<Py<$class_name>> {
+                let parsed = self.path.get().parsed_parts();
+                let parent_parsed = ParsedParts {
+                    drive: parsed.drive.clone(),
+                    root: parsed.root.clone(),
+                    parts: parsed.parts[..parsed.parts.len() - idx - 1].to_vec(),
+                };
+                Py::new(py, $class_name::from_parsed_parts(parent_parsed))
             }
+        }
 
-            fn _segment_matches(&self, segment: &str, pattern: &str) -> PyResult<bool> {
-                if pattern == "*" {
-                    return Ok(true);
+        #[pymethods]
+        impl $parents_name {
+            fn __len__(&self) -> usize {
+                self.path.get().parsed_parts().parts.len()
+            }
+
+            fn __getitem__(&self, py: Python, idx: &Bound<PyAny>) -> PyResult<Py<PyAny>> {
+                let len = self.__len__();
+                if let Ok(slice) = idx.cast::<PySlice>() {
+                    let indices = slice.indices(len as isize)?;
+                    let parents = (0..indices.slicelength)
+                        .map(|i| {
+                            self.parent_at(py, (indices.start + i as isize * indices.step) as usize)
+                        })
+                        .collect::<PyResult<Vec<_>>>()?;
+                    return Ok(PyTuple::new(py, parents)?.into_any().unbind());
                 }
 
-                let mut s_idx = 0;
-                let mut p_idx = 0;
-                let s_chars: Vec<char> = segment.chars().collect();
-                let p_chars: Vec<char> = pattern.chars().collect();
-
-                while p_idx < p_chars.len() {
-                    match p_chars[p_idx] {
-                        '*' => {
-                            if p_idx + 1 >= p_chars.len() {
-                                return Ok(true);
-                            }
-                            // Find next char after *
-                            let next_char = p_chars[p_idx + 1];
-                            while s_idx < s_chars.len() && s_chars[s_idx] != next_char {
-                                s_idx += 1;
-                            }
-                            if s_idx >= s_chars.len() {
-                                return Ok(false);
-                            }
-                            p_idx += 1;
-                        }
-                        '?' => {
-                            if s_idx >= s_chars.len() {
-                                return Ok(false);
-                            }
-                            s_idx += 1;
-                            p_idx += 1;
-                        }
-                        _ => {
-                            if s_idx >= s_chars.len() || s_chars[s_idx] != p_chars[p_idx] {
-                                return Ok(false);
-                            }
-                            s_idx += 1;
-                            p_idx += 1;
-                        }
-                    }
+                let signed: isize = idx.extract()?;
+                let resolved = if signed < 0 {
+                    signed + len as isize
+                } else {
+                    signed
+                };
+                if resolved < 0 || resolved >= len as isize {
+                    return Err(pyo3::exceptions::PyIndexError::new_err(signed));
                 }
+                Ok(self.parent_at(py, resolved as usize)?.into_any())
+            }
 
-                Ok(s_idx >= s_chars.len())
+            fn __iter__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyIterator>> {
+                let parents = (0..self.__len__())
+                    .map(|i| self.parent_at(py, i))
+                    .collect::<PyResult<Vec<_>>>()?;
+                PyList::new(py, parents)?.try_iter()
+            }
+
+            fn __repr__(&self) -> String {
+                format!("<{}.parents>", $py_name)
             }
         }
     };
@@ -595,5 +957,15 @@ macro_rules! create_pure_path_class {
 // GENERATE CLASSES
 // ============================================================================
 
-create_pure_path_class!(PurePosixPath, PosixSeparator, "PurePosixPath");
-create_pure_path_class!(PureWindowsPath, WindowsSeparator, "PureWindowsPath");
+create_pure_path_class!(
+    PurePosixPath,
+    PurePosixPathParents,
+    PosixSeparator,
+    "PurePosixPath"
+);
+create_pure_path_class!(
+    PureWindowsPath,
+    PureWindowsPathParents,
+    WindowsSeparator,
+    "PureWindowsPath"
+);
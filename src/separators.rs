@@ -1,4 +1,5 @@
 use crate::core::ParsedParts;
+use std::borrow::Cow;
 
 pub struct PosixSeparator;
 pub struct WindowsSeparator;
@@ -6,11 +7,7 @@ pub struct WindowsSeparator;
 impl PosixSeparator {
     pub const SEP: char = '/';
     pub const MODULE_NAME: &'static str = "posixpath";
-
-    /// On Posix, no normalization needed
-    pub fn normalize_path(path: &str) -> String {
-        path.to_string()
-    }
+    pub const CASE_SENSITIVE: bool = true;
 
     /// On Posix, case-sensitive: return as-is
     pub fn normalize_case(path: &str) -> String {
@@ -28,7 +25,13 @@ impl PosixSeparator {
     }
 
     pub fn splitroot(path: &str) -> (String, String, String) {
-        if let Some(rest) = path.strip_prefix(Self::SEP) {
+        // POSIX allows an implementation-defined meaning for exactly two
+        // leading slashes, so "//" is kept as root while "///" collapses to "/"
+        if let Some(rest) = path.strip_prefix("//")
+            && !rest.starts_with(Self::SEP)
+        {
+            (String::new(), "//".to_string(), rest.to_string())
+        } else if let Some(rest) = path.strip_prefix(Self::SEP) {
             (String::new(), Self::SEP.to_string(), rest.to_string())
         } else {
             (String::new(), String::new(), path.to_string())
@@ -60,6 +63,16 @@ impl PosixSeparator {
         !parsed.root.is_empty()
     }
 
+    /// Whether a single component contains a separator
+    pub fn contains_separator(part: &str) -> bool {
+        part.contains(Self::SEP)
+    }
+
+    /// No names are reserved on Posix
+    pub fn is_reserved(_parsed: &ParsedParts) -> bool {
+        false
+    }
+
     /// Format ParsedParts back to a string path
     /// Equivalent to Python's _format_parsed_parts
     pub fn format_parsed_parts(parsed: &ParsedParts) -> String {
@@ -71,14 +84,6 @@ impl PosixSeparator {
                 parsed.root,
                 parsed.parts.join(&Self::SEP.to_string())
             )
-        } else if !parsed.parts.is_empty()
-            && parsed.parts[0].len() >= 2
-            && parsed.parts[0].as_bytes()[1] == b':'
-        {
-            // First part looks like a drive letter - add "." prefix
-            let mut parts_with_dot = vec![".".to_string()];
-            parts_with_dot.extend(parsed.parts.clone());
-            parts_with_dot.join(&Self::SEP.to_string())
         } else {
             // No anchor, just join parts
             let joined = parsed.parts.join(&Self::SEP.to_string());
@@ -94,10 +99,16 @@ impl PosixSeparator {
 impl WindowsSeparator {
     pub const SEP: char = '\\';
     pub const MODULE_NAME: &'static str = "ntpath";
+    pub const CASE_SENSITIVE: bool = false;
 
     /// Normalize a path by converting / to \\ for Windows
-    pub fn normalize_path(path: &str) -> String {
-        path.replace(PosixSeparator::SEP, &Self::SEP.to_string())
+    /// Borrows the input when it has no "/", the common case for Windows paths
+    pub fn normalize_path(path: &str) -> Cow<'_, str> {
+        if path.contains(PosixSeparator::SEP) {
+            Cow::Owned(path.replace(PosixSeparator::SEP, &Self::SEP.to_string()))
+        } else {
+            Cow::Borrowed(path)
+        }
     }
 
     /// On Windows, case-insensitive: convert to lowercase
@@ -120,20 +131,32 @@ impl WindowsSeparator {
         // Handle UNC paths (\\server\share)
         if let Some(rest) = path.strip_prefix("\\\\") {
             // UNC path: \\server\share\file
-            // Need to find the share part
-            let parts: Vec<&str> = rest.split([Self::SEP, PosixSeparator::SEP]).collect();
-            if parts.len() >= 2 {
-                // \\server\share is the drive, \ is root, rest is the path
-                let drive = format!("\\\\{}\\{}", parts[0], parts[1]);
-                let body = parts[2..].join(&Self::SEP.to_string());
-                (drive, Self::SEP.to_string(), body)
-            } else if parts.len() == 1 {
+            // The drive runs up to the separator that follows the share name
+            let Some(server_end) = rest.find(Self::SEP) else {
                 // Just \\server without share
-                let drive = format!("\\\\{}", parts[0]);
-                (drive, String::new(), String::new())
-            } else {
-                // Edge case: just \\
-                (String::new(), "\\\\".to_string(), String::new())
+                return (path.to_string(), String::new(), String::new());
+            };
+            let (server, after_server) = (&rest[..server_end], &rest[server_end + 1..]);
+            match after_server.find(Self::SEP) {
+                Some(share_end) => {
+                    let drive_len = 2 + server_end + 1 + share_end;
+                    (
+                        path[..drive_len].to_string(),
+                        Self::SEP.to_string(),
+                        path[drive_len + 1..].to_string(),
+                    )
+                }
+                None => {
+                    // A complete \\server\share still gets a root, like pathlib does,
+                    // but "\\\" or "\\server\" are left as a bare drive
+                    let complete = !matches!(server, "" | "?" | ".") && !after_server.is_empty();
+                    let root = if complete {
+                        Self::SEP.to_string()
+                    } else {
+                        String::new()
+                    };
+                    (path.to_string(), root, String::new())
+                }
             }
         } else if path.len() >= 2 && path.as_bytes()[1] == b':' {
             // Drive letter: "C:..."
@@ -174,8 +197,62 @@ impl WindowsSeparator {
     }
 
     pub fn is_absolute(parsed: &ParsedParts) -> bool {
-        // On Windows, absolute means has a drive letter
-        !parsed.drive.is_empty()
+        // On Windows, absolute means a drive followed by a root (C:\),
+        // or a UNC/device drive, which is always anchored
+        !parsed.drive.is_empty() && (!parsed.root.is_empty() || parsed.drive.starts_with("\\\\"))
+    }
+
+    /// Whether a single component contains a separator, "/" included
+    pub fn contains_separator(part: &str) -> bool {
+        part.contains([Self::SEP, PosixSeparator::SEP])
+    }
+
+    /// Whether any component is a reserved device name or unusable as a file name
+    /// Equivalent to Python's ntpath.isreserved
+    pub fn is_reserved(parsed: &ParsedParts) -> bool {
+        parsed
+            .parts
+            .iter()
+            .rev()
+            .any(|name| Self::is_reserved_name(name))
+    }
+
+    fn is_reserved_name(name: &str) -> bool {
+        // Trailing dots and spaces are stripped by Windows
+        if name.ends_with(['.', ' ']) {
+            return name != "." && name != "..";
+        }
+        if name
+            .chars()
+            .any(|c| c < ' ' || matches!(c, '"' | '*' | '/' | ':' | '<' | '>' | '?' | '\\' | '|'))
+        {
+            return true;
+        }
+        // Device names stay reserved with any extension: "NUL.txt", "com1 .log"
+        let base = name.split('.').next().unwrap_or_default();
+        let base = base.trim_end_matches(' ').to_uppercase();
+        match base.as_str() {
+            "CON" | "PRN" | "AUX" | "NUL" | "CONIN$" | "CONOUT$" => true,
+            _ => base
+                .strip_prefix("COM")
+                .or_else(|| base.strip_prefix("LPT"))
+                .is_some_and(|digit| {
+                    matches!(
+                        digit,
+                        "1" | "2"
+                            | "3"
+                            | "4"
+                            | "5"
+                            | "6"
+                            | "7"
+                            | "8"
+                            | "9"
+                            | "\u{b9}"
+                            | "\u{b2}"
+                            | "\u{b3}"
+                    )
+                }),
+        }
     }
 
     /// Format ParsedParts back to a string path
@@ -0,0 +1,66 @@
+use std::fmt::Write;
+
+/// Percent-encode a path for a file URI
+/// Equivalent to Python's urllib.parse.quote_from_bytes(os.fsencode(path)):
+/// unreserved characters and "/" are kept, every other UTF-8 byte is escaped
+pub fn quote(path: &str) -> String {
+    let mut quoted = String::with_capacity(path.len());
+    for byte in path.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'_' | b'.' | b'-' | b'~' | b'/') {
+            quoted.push(byte as char);
+        } else {
+            let _ = write!(quoted, "%{:02X}", byte);
+        }
+    }
+    quoted
+}
+
+/// Decode percent-escapes back to raw bytes
+/// Equivalent to Python's urllib.parse.unquote_to_bytes: malformed escapes are kept as-is
+pub fn unquote_to_bytes(quoted: &str) -> Vec<u8> {
+    let bytes = quoted.as_bytes();
+    let mut unquoted = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let escaped = (bytes[i] == b'%')
+            .then(|| bytes.get(i + 1..i + 3))
+            .flatten()
+            .filter(|hex| hex.iter().all(u8::is_ascii_hexdigit))
+            .and_then(|hex| u8::from_str_radix(std::str::from_utf8(hex).ok()?, 16).ok());
+        match escaped {
+            Some(byte) => {
+                unquoted.push(byte);
+                i += 3;
+            }
+            None => {
+                unquoted.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+    unquoted
+}
+
+/// Strip the "file:" scheme and authority from a file URI, like pathlib's Path.from_uri
+/// Returns None when the URI has another scheme
+pub fn file_uri_path(uri: &str) -> Option<String> {
+    let mut path = uri.strip_prefix("file:")?;
+    if path.starts_with("///") {
+        // Empty authority
+        path = &path[2..];
+    } else if path.starts_with("//localhost/") {
+        path = &path[11..];
+    }
+    if path.starts_with("///")
+        || (path.starts_with('/') && matches!(path.chars().nth(2), Some(':' | '|')))
+    {
+        // Slash before a DOS drive or UNC share
+        path = &path[1..];
+    }
+    // "C|" is an old spelling of the drive "C:"
+    let mut chars = path.chars();
+    match (chars.next(), chars.next()) {
+        (Some(letter), Some('|')) => Some(format!("{}:{}", letter, chars.as_str())),
+        _ => Some(path.to_string()),
+    }
+}
@@ -0,0 +1,179 @@
+"""Parity tests for PurePath.match and PurePath.full_match against pathlib."""
+
+import pathlib
+
+import pyochain as pc
+import pyopath
+import pytest
+
+PATHS = pc.Seq(
+    (
+        "a",
+        "a.py",
+        "b/a.py",
+        "a/b/c.py",
+        "a/b/c/d.py",
+        "/a/b.py",
+        "/a/b/c.py",
+        ".hidden",
+        "a/.b/c.txt",
+        "abc",
+        "a1",
+        "a]",
+        "A/B.PY",
+        ".",
+        "/",
+    )
+)
+
+PATTERNS = pc.Seq(
+    (
+        "*",
+        "*.py",
+        "a.py",
+        "a/*.py",
+        "*/*.py",
+        "**",
+        "**/*.py",
+        "a/**",
+        "a/**/*.py",
+        "a/**/**/c.py",
+        "**/c/**",
+        "/**/*.py",
+        "/a/*.py",
+        "/*/*/*.py",
+        "?.py",
+        "a?",
+        "??",
+        "a[0-9]",
+        "a[!0-9]*",
+        "[abc]*",
+        "[!a]*",
+        "a[]]",
+        "a[!]]",
+        "a[",
+        "[c-a]*",
+        "a/**b/*.py",
+        ".*",
+        "*.PY",
+        "A/*.py",
+    )
+)
+
+
+class TestMatchParity:
+    """Compare match results with pathlib for many (path, pattern) pairs."""
+
+    @pytest.mark.parametrize("case_sensitive", [None, True, False])
+    def test_match_posix(self, case_sensitive: bool | None) -> None:
+        """Test match on POSIX paths."""
+
+        def _check(p: str) -> None:
+            def _check_pattern(pattern: str) -> None:
+                expected = pathlib.PurePosixPath(p).match(
+                    pattern, case_sensitive=case_sensitive
+                )
+                result = pyopath.PurePosixPath(p).match(
+                    pattern, case_sensitive=case_sensitive
+                )
+                assert result == expected, (p, pattern)
+
+            PATTERNS.iter().for_each(_check_pattern)
+
+        PATHS.iter().for_each(_check)
+
+    @pytest.mark.parametrize("case_sensitive", [None, True, False])
+    def test_full_match_posix(self, case_sensitive: bool | None) -> None:
+        """Test full_match on POSIX paths."""
+
+        def _check(p: str) -> None:
+            def _check_pattern(pattern: str) -> None:
+                expected = pathlib.PurePosixPath(p).full_match(
+                    pattern, case_sensitive=case_sensitive
+                )
+                result = pyopath.PurePosixPath(p).full_match(
+                    pattern, case_sensitive=case_sensitive
+                )
+                assert result == expected, (p, pattern)
+
+            PATTERNS.iter().for_each(_check_pattern)
+
+        PATHS.iter().for_each(_check)
+
+    def test_match_windows(self) -> None:
+        """Test match and full_match on Windows paths."""
+
+        def _check(p: str) -> None:
+            def _check_pattern(pattern: str) -> None:
+                std = pathlib.PureWindowsPath(p)
+                ours = pyopath.PureWindowsPath(p)
+                assert ours.match(pattern) == std.match(pattern), (p, pattern)
+                assert ours.full_match(pattern) == std.full_match(pattern), (
+                    p,
+                    pattern,
+                )
+
+            PATTERNS.iter().for_each(_check_pattern)
+
+        PATHS.iter().for_each(_check)
+
+    def test_match_double_star_does_not_span(self) -> None:
+        """Test that ** spans components in full_match but not in match."""
+        p = pyopath.PurePosixPath("a/b/c/d.py")
+        assert not p.match("a/**/d.py")
+        assert p.match("b/**/d.py")
+        assert p.full_match("a/**/d.py")
+        assert p.full_match("**/*.py")
+        assert not p.full_match("*.py")
+
+    def test_match_empty_pattern_raises(self) -> None:
+        """Test that match rejects an empty pattern like pathlib."""
+        with pytest.raises(ValueError, match="empty pattern"):
+            pyopath.PurePosixPath("a").match("")
+
+    def test_match_path_pattern(self) -> None:
+        """Test that path objects are accepted as patterns."""
+        p = pyopath.PurePosixPath("a/b.py")
+        assert p.match(pyopath.PurePosixPath("*.py"))
+        assert p.full_match(pyopath.PurePosixPath("a/*.py"))
+
+    def test_question_mark_matches_one_character(self) -> None:
+        """Test that ? matches exactly one non-separator character."""
+        assert pyopath.PurePosixPath("file1.rs").full_match("file?.rs")
+        assert not pyopath.PurePosixPath("file.rs").full_match("file?.rs")
+        assert not pyopath.PurePosixPath("file12.rs").full_match("file?.rs")
+        assert not pyopath.PurePosixPath("a/b").full_match("a?b")
+        assert pyopath.PurePosixPath("img_01.png").match("img_??.png")
+
+    def test_character_classes(self) -> None:
+        """Test ranges, sets, negation and literal brackets in patterns."""
+        assert pyopath.PurePosixPath("c.log").match("[a-f]*.log")
+        assert not pyopath.PurePosixPath("x.log").match("[a-f]*.log")
+        assert pyopath.PurePosixPath("reportA.txt").match("report[!0-9].txt")
+        assert not pyopath.PurePosixPath("report1.txt").match("report[!0-9].txt")
+        assert pyopath.PurePosixPath("b").match("[abc]")
+        assert pyopath.PurePosixPath("]").match("[]]")
+        assert pyopath.PurePosixPath("a[").match("a[")
+        assert not pyopath.PurePosixPath("a/b").match("a[/]b")
+        assert not pyopath.PurePosixPath("a/b").match("a[!x]b")
+
+    def test_full_match_whole_path(self) -> None:
+        """Test that full_match anchors the pattern at both ends of the path."""
+        p = pyopath.PurePosixPath("a/b/c.py")
+        assert p.full_match("a/**/*.py")
+        assert p.full_match("a/b/c.py")
+        assert not p.full_match("*.py")
+        assert not p.full_match("b/c.py")
+        assert not p.full_match("a/b")
+        assert not pyopath.PurePosixPath("/a/b/c.py").full_match("a/**/*.py")
+        assert pyopath.PurePosixPath("/a/b/c.py").full_match("/a/**/*.py")
+        assert pyopath.PureWindowsPath("C:\\a\\b\\c.py").full_match("C:/a/**/*.PY")
+
+    def test_bracket_escapes(self) -> None:
+        """Test that [[], [*] and [?] match the literal special characters."""
+        assert pyopath.PurePosixPath("[x]").full_match("[[]*")
+        assert not pyopath.PurePosixPath("x]").full_match("[[]*")
+        assert pyopath.PurePosixPath("a*b").full_match("a[*]b")
+        assert not pyopath.PurePosixPath("axb").full_match("a[*]b")
+        assert pyopath.PurePosixPath("what?").match("what[?]")
+        assert not pyopath.PurePosixPath("whats").match("what[?]")
@@ -1,12 +1,28 @@
 """Tests for PurePath and its subclasses."""
 
+import copy
+import ntpath
+import os
 import pathlib
+import pickle
+import posixpath
+from collections.abc import Callable
 
 import pyochain as pc
 import pyopath
 import pytest
 
 
+class _FsPath:
+    """Minimal os.PathLike implementation."""
+
+    def __init__(self, path: str | bytes) -> None:
+        self._path = path
+
+    def __fspath__(self) -> str | bytes:
+        return self._path
+
+
 class TestPurePathProperties:
     """Test PurePath property behavior matches pathlib."""
 
@@ -34,6 +50,17 @@ class TestPurePathProperties:
 
         test_paths.iter().for_each(_check)
 
+    def test_parts_tuple_comparison(self) -> None:
+        """Test parts is a real tuple comparable to a literal tuple."""
+        posix_parts = pyopath.PurePosixPath("/a/b").parts
+        assert isinstance(posix_parts, tuple)
+        assert posix_parts == ("/", "a", "b")
+        assert pyopath.PurePosixPath("a/b").parts == ("a", "b")
+        assert pyopath.PureWindowsPath("C:/a/b").parts == ("C:\\", "a", "b")
+        assert pyopath.PureWindowsPath("C:a").parts == ("C:", "a")
+        unc_parts = pyopath.PureWindowsPath("//srv/share/a").parts
+        assert unc_parts == ("\\\\srv\\share\\", "a")
+
     def test_name(self, test_paths: pc.Seq[str]) -> None:
         """Test name property."""
 
@@ -42,6 +69,35 @@ class TestPurePathProperties:
 
         test_paths.iter().for_each(_check)
 
+    def test_name_trailing_separator(self) -> None:
+        """Test name property on paths ending in a separator."""
+        paths = pc.Seq(("a/b/", "a/b//", "/a/b/", "b/", "a\\b\\", "C:\\a\\b\\"))
+
+        def _check(p: str) -> None:
+            assert pyopath.PurePosixPath(p).name == pathlib.PurePosixPath(p).name
+            assert pyopath.PureWindowsPath(p).name == pathlib.PureWindowsPath(p).name
+
+        paths.iter().for_each(_check)
+        assert pyopath.PurePosixPath("a/b/").name == "b"
+        assert pyopath.PureWindowsPath("a\\b\\").name == "b"
+
+    def test_posix_backslash_is_literal(self) -> None:
+        """Test POSIX parsing never treats a backslash as a separator."""
+        paths = pc.Seq(("C:\\x", "a\\b", "\\\\server\\share", "a\\b/c\\d", "\\"))
+
+        def _check(p: str) -> None:
+            pyopath_p = pyopath.PurePosixPath(p)
+            pathlib_p = pathlib.PurePosixPath(p)
+            assert pyopath_p.parts == pathlib_p.parts
+            assert pyopath_p.name == pathlib_p.name
+            assert pyopath_p.drive == pathlib_p.drive
+            assert pyopath_p.root == pathlib_p.root
+
+        paths.iter().for_each(_check)
+        assert pyopath.PurePosixPath("C:\\x").name == "C:\\x"
+        assert pyopath.PurePosixPath("C:\\x").parts == ("C:\\x",)
+        assert not pyopath.PurePosixPath("C:\\x").is_absolute()
+
     def test_suffix(self, test_paths: pc.Seq[str]) -> None:
         """Test suffix property."""
 
@@ -58,6 +114,34 @@ class TestPurePathProperties:
 
         test_paths.iter().for_each(_check)
 
+    def test_dot_heavy_names(self) -> None:
+        """Test suffix, stem and suffixes on names made mostly of dots."""
+        names = pc.Seq(("...", "....", ". .", "..a", "a..", "a.", ".a.b", "a...b"))
+
+        def _check(name: str) -> None:
+            p = pyopath.PurePosixPath(name)
+            expected = pathlib.PurePosixPath(name)
+            assert p.suffix == expected.suffix
+            assert p.stem == expected.stem
+            assert list(p.suffixes) == expected.suffixes
+
+        names.iter().for_each(_check)
+        assert pyopath.PurePosixPath("...").suffix == ""
+        assert pyopath.PurePosixPath("...").stem == "..."
+
+    def test_dot_components_retained(self) -> None:
+        """Test only '.' and empty components are dropped while parsing."""
+        paths = pc.Seq(("a/.../b", "a/../b", "./a/./b/.", "a//b", "../..", "/.../.."))
+
+        def _check(p: str) -> None:
+            assert pyopath.PurePosixPath(p).parts == pathlib.PurePosixPath(p).parts
+            assert pyopath.PureWindowsPath(p).parts == pathlib.PureWindowsPath(p).parts
+
+        paths.iter().for_each(_check)
+        assert pyopath.PurePosixPath("a/.../b").parts == ("a", "...", "b")
+        assert pyopath.PurePosixPath("a/../b").parts == ("a", "..", "b")
+        assert pyopath.PureWindowsPath("C:\\...\\..").parts == ("C:\\", "...", "..")
+
     def test_stem(self, test_paths: pc.Seq[str]) -> None:
         """Test stem property."""
 
@@ -81,6 +165,37 @@ class TestPurePathProperties:
         pathlib_parents = pc.Iter(pathlib.PurePath(path).parents).map(str).collect()
         assert pyopath_parents.eq(pathlib_parents)
 
+    def test_parents_sequence(self) -> None:
+        """Test len, negative indices, slices and repr of parents."""
+        p = pyopath.PurePosixPath("/a/b/c/d")
+        expected = pathlib.PurePosixPath("/a/b/c/d").parents
+
+        assert len(p.parents) == len(expected) == 4
+        assert repr(p.parents) == repr(expected) == "<PurePosixPath.parents>"
+        for idx in (0, 3, -1, -4):
+            assert str(p.parents[idx]) == str(expected[idx])
+        for sl in (slice(None), slice(1, 3), slice(None, None, -1), slice(5, 0, -2)):
+            assert tuple(map(str, p.parents[sl])) == tuple(map(str, expected[sl]))
+        assert isinstance(p.parents[1:], tuple)
+        assert pyopath.PurePosixPath("/a") in p.parents
+
+        # The last parent of a relative path is '.', as in pathlib
+        relative = pyopath.PurePosixPath("a/b/c")
+        assert relative.parents[-2] == pyopath.PurePosixPath("a")
+        assert str(relative.parents[-1]) == "."
+        windows = pyopath.PureWindowsPath("C:/x/y")
+        assert repr(windows.parents) == "<PureWindowsPath.parents>"
+        assert str(windows.parents[-1]) == "C:\\"
+
+    def test_parents_index_error(self) -> None:
+        """Test out-of-range parents indices raise IndexError like pathlib."""
+        p = pyopath.PurePosixPath("/a/b")
+        with pytest.raises(IndexError):
+            p.parents[2]
+        assert len(pyopath.PurePosixPath("/").parents) == 0
+        with pytest.raises(IndexError):
+            pyopath.PurePosixPath(".").parents[0]
+
     def test_anchor(self, test_paths: pc.Seq[str]) -> None:
         """Test anchor property."""
 
@@ -121,18 +236,135 @@ class TestPurePathMethods:
 
         paths.iter().for_each(_check)
 
+    def test_normpath(self) -> None:
+        """Test normpath collapses '..' like os.path.normpath."""
+        paths = pc.Seq(
+            ("a/b/../../c", "/../x", "..//x", "./x", "a/../..", "//a/../b", "a/b/..")
+        )
+
+        def _check(p: str) -> None:
+            assert str(pyopath.PurePosixPath(p).normpath()) == posixpath.normpath(p)
+            assert str(pyopath.PureWindowsPath(p).normpath()) == ntpath.normpath(p)
+
+        paths.iter().for_each(_check)
+        assert str(pyopath.PurePosixPath("a/b/../../c").normpath()) == "c"
+        assert str(pyopath.PurePosixPath("/../x").normpath()) == "/x"
+        assert str(pyopath.PurePosixPath("..//x").normpath()) == "../x"
+        assert str(pyopath.PureWindowsPath("C:/a/../..").normpath()) == "C:\\"
+        assert str(pyopath.PureWindowsPath("C:a/../..").normpath()) == "C:.."
+
+    def test_is_empty_and_is_dot(self) -> None:
+        """Test is_empty and is_dot predicates."""
+        assert pyopath.PurePosixPath(".").is_dot()
+        assert pyopath.PurePosixPath().is_empty()
+        assert pyopath.PurePosixPath("./.").is_empty()
+        assert not pyopath.PurePosixPath("/").is_dot()
+        assert not pyopath.PurePosixPath("a").is_empty()
+        assert not pyopath.PurePosixPath("..").is_dot()
+        assert pyopath.PureWindowsPath(".").is_dot()
+        assert not pyopath.PureWindowsPath("C:").is_dot()
+        assert not pyopath.PureWindowsPath("\\").is_empty()
+
     def test_is_relative_to(self) -> None:
         """Test is_relative_to method."""
         assert pyopath.PurePath("/usr/local/bin").is_relative_to("/usr")
         assert not pyopath.PurePath("/usr/local/bin").is_relative_to("/etc")
         assert pyopath.PurePath("foo/bar").is_relative_to("foo")
 
+    def test_is_relative_to_parity(self) -> None:
+        """Test is_relative_to with strings and paths against pathlib."""
+        cases = pc.Seq(
+            (
+                ("/a/b/c", "/a"),
+                ("/a/b", "/a/b/c"),
+                ("a/b", "/a"),
+                ("C:/A/b", "c:/a"),
+                ("C:/a", "D:/a"),
+                ("//srv/share/x", "//SRV/SHARE"),
+            )
+        )
+
+        def _check(case: tuple[str, str]) -> None:
+            path, other = case
+            for ours, std in (
+                (pyopath.PurePosixPath, pathlib.PurePosixPath),
+                (pyopath.PureWindowsPath, pathlib.PureWindowsPath),
+            ):
+                expected = std(path).is_relative_to(other)
+                assert ours(path).is_relative_to(other) == expected, case
+                assert ours(path).is_relative_to(ours(other)) == expected, case
+
+        cases.iter().for_each(_check)
+
+    def test_starts_with_and_ends_with(self) -> None:
+        """Test component-wise starts_with and ends_with across flavors."""
+        p = pyopath.PurePosixPath("/a/b/c")
+        assert p.starts_with("/a/b")
+        assert p.starts_with(pyopath.PurePosixPath("/"))
+        assert not p.starts_with("/a/bc")
+        assert not p.starts_with("a")
+        assert p.ends_with("b/c")
+        assert p.ends_with(pyopath.PurePosixPath("c"))
+        assert p.ends_with("/a/b/c")
+        assert not p.ends_with("/b/c")
+        assert not p.ends_with("a/b/c/d")
+        assert not p.ends_with("B/C")
+        assert p.ends_with("b/c/")
+        assert not pyopath.PurePosixPath("/a/xb/c").ends_with("b/c")
+
+        w = pyopath.PureWindowsPath("C:\\Users\\Me\\File.TXT")
+        assert w.starts_with("c:/users")
+        assert w.ends_with("me/file.txt")
+        assert w.ends_with("C:/USERS/me/file.txt")
+        assert not w.ends_with("D:/Users/Me/File.TXT")
+        assert not w.starts_with("Users")
+
     def test_relative_to(self) -> None:
         """Test relative_to method."""
         assert str(pyopath.PurePath("/usr/local/bin").relative_to("/usr")) == str(
             pathlib.PurePath("/usr/local/bin").relative_to("/usr")
         )
 
+    def test_relative_to_trailing_separator(self) -> None:
+        """Test a trailing separator on the base is ignored."""
+        assert pyopath.PurePosixPath("/a/b/c").relative_to("/a/b/") == (
+            pyopath.PurePosixPath("c")
+        )
+        assert pyopath.PurePosixPath("a/b").relative_to("a//") == (
+            pyopath.PurePosixPath("b")
+        )
+        assert pyopath.PureWindowsPath("C:\\a\\b\\c").relative_to("C:\\a\\b\\") == (
+            pyopath.PureWindowsPath("c")
+        )
+        assert str(pyopath.PureWindowsPath("C:/a/b/c").relative_to("c:/A/b/")) == (
+            str(pathlib.PureWindowsPath("C:/a/b/c").relative_to("c:/A/b/"))
+        )
+
+    def test_relative_to_walk_up(self) -> None:
+        """Test relative_to with walk_up produces an anchorless '..' path."""
+        result = pyopath.PurePosixPath("/a/b").relative_to("/a/c", walk_up=True)
+        assert str(result) == "../b"
+        assert not result.is_absolute()
+        assert result.anchor == ""
+        assert str(pyopath.PurePosixPath("/a/c") / result) == "/a/c/../b"
+
+        cases = pc.Seq(
+            (
+                ("/a/b", "/a/b/c/d"),
+                ("/a/b", "/a"),
+                ("a", "b"),
+                ("/a/b", "/"),
+            )
+        )
+
+        def _check(case: tuple[str, str]) -> None:
+            path, other = case
+            assert str(
+                pyopath.PurePosixPath(path).relative_to(other, walk_up=True)
+            ) == str(pathlib.PurePosixPath(path).relative_to(other, walk_up=True))
+
+        cases.iter().for_each(_check)
+
     def test_joinpath(self) -> None:
         """Test joinpath method."""
         base = "/home/user"
@@ -141,12 +373,97 @@ class TestPurePathMethods:
         pathlib_result = str(pathlib.PurePath(base).joinpath(*parts))
         assert pyopath_result == pathlib_result
 
+    def test_joinpath_redundant_separators(self) -> None:
+        """Test joinpath with leading, trailing and duplicate separators."""
+        segments = pc.Seq(
+            (
+                ("a/", "b"),
+                ("a", "b/"),
+                ("a//", "b"),
+                ("a", "b//c/"),
+                ("a/./b", "c"),
+                ("/a", "/b"),
+                ("a", "//b"),
+            )
+        )
+
+        def _check(parts: tuple[str, ...]) -> None:
+            pyopath_result = pyopath.PurePosixPath("x").joinpath(*parts)
+            pathlib_result = pathlib.PurePosixPath("x").joinpath(*parts)
+            assert str(pyopath_result) == str(pathlib_result)
+            assert tuple(pyopath_result.parts) == pathlib_result.parts
+
+        segments.iter().for_each(_check)
+        assert str(pyopath.PurePosixPath("x").joinpath("a/", "b")) == "x/a/b"
+
+    def test_joinpath_from_dot(self) -> None:
+        """Test joining onto the current directory path."""
+        dot = pyopath.PurePosixPath(".")
+        assert str(dot / "a") == "a"
+        assert str(dot.joinpath("a", "b")) == "a/b"
+        assert dot.joinpath("a", "b") == pyopath.PurePosixPath("a/b")
+        assert str(dot / ".") == "."
+        assert str("a" / dot) == "a"
+        assert str(pyopath.PureWindowsPath(".") / "a") == "a"
+
+    def test_join_preserves_double_slash_root(self) -> None:
+        """Test that the POSIX '//' root survives joins while '///' collapses."""
+        roots = pc.Seq(("//a", "//a/", "//", "///a"))
+
+        def _check(p: str) -> None:
+            pyopath_result = pyopath.PurePosixPath(p) / "b"
+            pathlib_result = pathlib.PurePosixPath(p) / "b"
+            assert str(pyopath_result) == str(pathlib_result)
+            assert pyopath_result.anchor == pathlib_result.anchor
+
+        roots.iter().for_each(_check)
+        joined = pyopath.PurePosixPath("//a") / "b"
+        assert str(joined) == "//a/b"
+        assert joined.anchor == "//"
+
     def test_truediv_operator(self) -> None:
         """Test / operator."""
         pyopath_result = str(pyopath.PurePath("/home") / "user" / "file.txt")
         pathlib_result = str(pathlib.PurePath("/home") / "user" / "file.txt")
         assert pyopath_result == pathlib_result
 
+    def test_truediv_path_objects(self) -> None:
+        """Test / operator with path objects on either side."""
+        joined = pyopath.PurePosixPath("a") / pyopath.PurePosixPath("b")
+        assert isinstance(joined, pyopath.PurePosixPath)
+        assert str(joined) == "a/b"
+        assert str(pyopath.PurePosixPath("a") / pathlib.PurePosixPath("b")) == "a/b"
+        assert str("a" / pyopath.PurePosixPath("b")) == "a/b"
+
+        converted = pyopath.PurePosixPath("b/c").to_windows()
+        assert str(pyopath.PureWindowsPath("C:/a") / converted) == "C:\\a\\b\\c"
+        posix = pyopath.PurePosixPath("a") / pyopath.PureWindowsPath("b\\c").to_posix()
+        assert str(posix) == "a/b/c"
+
+    def test_join_other_flavor_raises(self) -> None:
+        """Test joining a path of the other flavor raises a clear TypeError."""
+        posix = pyopath.PurePosixPath("a")
+        windows = pyopath.PureWindowsPath("b\\c")
+
+        with pytest.raises(TypeError, match=r"convert it with to_posix\(\) first"):
+            posix / windows  # type: ignore[operator]
+        with pytest.raises(TypeError, match="cannot join PureWindowsPath"):
+            posix.joinpath("x", windows)
+        with pytest.raises(TypeError, match=r"convert it with to_windows\(\) first"):
+            windows / posix  # type: ignore[operator]
+        with pytest.raises(TypeError, match="cannot join PurePosixPath"):
+            windows.joinpath(posix)
+
+        # Explicit construction still converts between flavors
+        assert str(pyopath.PurePosixPath(posix, windows)) == "a/b/c"
+
+    def test_truediv_unsupported_type(self) -> None:
+        """Test / operator raises TypeError for non path-like operands."""
+        with pytest.raises(TypeError):
+            pyopath.PurePosixPath("a") / 1  # type: ignore[operator]
+        with pytest.raises(TypeError):
+            1 / pyopath.PurePosixPath("a")  # type: ignore[operator]
+
     def test_with_name(self) -> None:
         """Test with_name method."""
         paths = pc.Seq(("/home/file.txt", "dir/doc.pdf", "test.py"))
@@ -158,6 +475,22 @@ class TestPurePathMethods:
 
         paths.iter().for_each(_check)
 
+    def test_with_name_fresh_caches(self) -> None:
+        """Test that with_name results don't reuse the original's cached values."""
+        original = pyopath.PurePosixPath("/a/b.txt")
+        # Populate the original's caches first
+        _ = (str(original), original.parts, hash(original))
+
+        renamed = original.with_name("c.py")
+        expected = pyopath.PurePosixPath("/a/c.py")
+        assert str(renamed) == "/a/c.py"
+        assert renamed.parts == ("/", "a", "c.py")
+        assert hash(renamed) == hash(expected)
+        assert renamed == expected
+        assert renamed.name == "c.py"
+        assert renamed.suffix == ".py"
+        assert str(original) == "/a/b.txt"
+
     def test_with_stem(self) -> None:
         """Test with_stem method."""
         paths = pc.Seq(("/home/file.txt", "dir/doc.pdf", "test.py"))
@@ -180,12 +513,337 @@ class TestPurePathMethods:
 
         paths.iter().for_each(_check)
 
+    def test_with_suffix_invalid(self) -> None:
+        """Test with_suffix rejects suffixes without a leading dot like pathlib."""
+        with pytest.raises(ValueError, match="^Invalid suffix 'txt'$"):
+            pyopath.PureWindowsPath("file").with_suffix("txt")
+        with pytest.raises(ValueError, match="has an empty name"):
+            pyopath.PurePosixPath("/").with_suffix(".txt")
+        assert str(pyopath.PurePosixPath("a.b").with_suffix("")) == "a"
+
+    def test_with_suffix_invalid_name(self) -> None:
+        """Test with_suffix rejects '.' names and names with a separator."""
+        with pytest.raises(ValueError) as empty_err:
+            pyopath.PurePosixPath("").with_suffix(".x")
+        assert str(empty_err.value) == "PurePosixPath('.') has an empty name"
+        # A backslash is an ordinary character on POSIX
+        assert pyopath.PurePosixPath("a").with_suffix(".a\\b").name == "a.a\\b"
+
     def test_as_posix(self) -> None:
         """Test as_posix method."""
         assert pyopath.PureWindowsPath("C:\\Users\\test").as_posix() == "C:/Users/test"
         assert pyopath.PurePath("/unix/path").as_posix() == "/unix/path"
 
 
+class TestPurePathFromParts:
+    """Test building paths from already-split components."""
+
+    def test_from_parts_round_trip(self) -> None:
+        """Test from_parts matches parsing the equivalent string."""
+        cases = pc.Seq(
+            (
+                ("", "/", ("usr", "local", "bin")),
+                ("", "", ("a", "b.txt")),
+                ("", "", ()),
+                ("", "//", ("a",)),
+            )
+        )
+
+        def _check(case: tuple[str, str, tuple[str, ...]]) -> None:
+            drive, root, parts = case
+            p = pyopath.PurePosixPath.from_parts(drive, root, parts)
+            assert p == pyopath.PurePosixPath(str(p))
+            assert p.drive == drive
+            assert p.root == root
+            assert str(p) == str(pathlib.PurePosixPath(drive + root, *parts))
+
+        cases.iter().for_each(_check)
+
+    def test_from_parts_windows(self) -> None:
+        """Test from_parts with Windows drives."""
+        p = pyopath.PureWindowsPath.from_parts("C:", "\\", ["Users", "test"])
+        assert str(p) == "C:\\Users\\test"
+        assert p.is_absolute()
+        unc = pyopath.PureWindowsPath.from_parts("\\\\srv\\share", "\\", ["a"])
+        assert str(unc) == "\\\\srv\\share\\a"
+        assert unc == pyopath.PureWindowsPath("//srv/share/a")
+
+    def test_from_parts_rejects_separators(self) -> None:
+        """Test from_parts rejects components containing separators."""
+        with pytest.raises(ValueError, match="separators"):
+            pyopath.PurePosixPath.from_parts("", "", ["a/b"])
+        with pytest.raises(ValueError, match="separators"):
+            pyopath.PureWindowsPath.from_parts("", "", ["a/b"])
+        with pytest.raises(ValueError, match="separators"):
+            pyopath.PureWindowsPath.from_parts("C:", "\\", ["a\\b"])
+        assert str(pyopath.PurePosixPath.from_parts("", "", ["a\\b"])) == "a\\b"
+
+    def test_from_parts_rejects_empty_and_dot(self) -> None:
+        """Test from_parts rejects components that parsing would drop."""
+        for part in ("", "."):
+            with pytest.raises(ValueError, match="must not be empty or '.'"):
+                pyopath.PurePosixPath.from_parts("", "/", ["a", part])
+            with pytest.raises(ValueError, match="must not be empty or '.'"):
+                pyopath.PureWindowsPath.from_parts("C:", "\\", [part])
+        p = pyopath.PurePosixPath.from_parts("", "/", ["a", "..", "b"])
+        assert p == pyopath.PurePosixPath("/a/../b")
+
+    def test_from_parts_rejects_invalid_anchor(self) -> None:
+        """Test the drive and root must be what the flavor would split off."""
+        posix_cases = (("X", "y"), ("", "a"), ("", "///"), ("C:", ""))
+        for drive, root in posix_cases:
+            with pytest.raises(ValueError, match="Invalid anchor"):
+                pyopath.PurePosixPath.from_parts(drive, root, ["a"])
+        windows_cases = (("X", "y"), ("C:", "/"), ("\\\\srv\\share", ""), ("C", ""))
+        for drive, root in windows_cases:
+            with pytest.raises(ValueError, match="Invalid anchor"):
+                pyopath.PureWindowsPath.from_parts(drive, root, ["a"])
+        assert str(pyopath.PureWindowsPath.from_parts("", "\\", ["a"])) == "\\a"
+        assert str(pyopath.PureWindowsPath.from_parts("D:", "", ["a"])) == "D:a"
+
+    def test_from_parts_rejects_null_byte(self) -> None:
+        """Test from_parts rejects NUL in the drive, root or any component."""
+        posix_cases = (("", "", ["a\0b"]), ("", "/\0", ["a"]), ("", "/", ["a", "\0"]))
+        for drive, root, parts in posix_cases:
+            with pytest.raises(ValueError, match="^embedded null byte$"):
+                pyopath.PurePosixPath.from_parts(drive, root, parts)
+        windows_cases = (("C\0:", "\\", ["a"]), ("C:", "\\", ["a\0"]))
+        for drive, root, parts in windows_cases:
+            with pytest.raises(ValueError, match="^embedded null byte$"):
+                pyopath.PureWindowsPath.from_parts(drive, root, parts)
+
+
+class TestPurePathCommonPath:
+    """Test common_path against os.path.commonpath."""
+
+    def test_common_path_posix(self) -> None:
+        """Test common ancestors of POSIX paths."""
+        cases = pc.Seq(
+            (
+                ("/usr/lib", "/usr/local"),
+                ("/usr/lib/", "/usr/lib/x", "/usr/lib/y/z"),
+                ("a/b", "a/c"),
+                ("a", "b"),
+                ("/a/./b", "/a/b/c"),
+                ("/a", "/b"),
+                ("//a",),
+                ("//a", "//a/b"),
+                ("//a/b", "/a/c"),
+            )
+        )
+
+        def _check(paths: tuple[str, ...]) -> None:
+            result = pyopath.PurePosixPath.common_path(paths)
+            assert str(result) == (posixpath.commonpath(paths) or ".")
+
+        cases.iter().for_each(_check)
+
+    def test_common_path_windows(self) -> None:
+        """Test common ancestors of Windows paths, case-folded."""
+        cases = pc.Seq(
+            (
+                ("C:/Users/A", "c:/users/b"),
+                ("C:\\a\\b", "C:\\a\\c"),
+                ("//srv/share/a", "//SRV/share/b"),
+            )
+        )
+
+        def _check(paths: tuple[str, ...]) -> None:
+            result = pyopath.PureWindowsPath.common_path(paths)
+            assert str(result) == ntpath.commonpath(paths)
+
+        cases.iter().for_each(_check)
+
+    def test_common_path_path_objects(self) -> None:
+        """Test common_path accepts path objects and iterators."""
+        paths = iter(("/a/b", pyopath.PurePosixPath("/a/c")))
+        result = pyopath.PurePosixPath.common_path(paths)
+        assert result == pyopath.PurePosixPath("/a")
+
+    def test_common_path_errors(self) -> None:
+        """Test common_path raises like os.path.commonpath."""
+        empty_sequence = r"^commonpath\(\) arg is an empty sequence$"
+        with pytest.raises(ValueError, match=empty_sequence):
+            pyopath.PurePosixPath.common_path([])
+        empty_iterable = r"^commonpath\(\) arg is an empty iterable$"
+        with pytest.raises(ValueError, match=empty_iterable):
+            pyopath.PureWindowsPath.common_path([])
+        with pytest.raises(ValueError, match="^Can't mix absolute and relative paths$"):
+            pyopath.PurePosixPath.common_path(["/a", "b"])
+        with pytest.raises(ValueError, match="^Paths don't have the same drive$"):
+            pyopath.PureWindowsPath.common_path(["C:/a", "D:/a"])
+        with pytest.raises(ValueError, match="^Can't mix rooted and not-rooted paths$"):
+            pyopath.PureWindowsPath.common_path(["/a", "b"])
+
+
+class TestPurePathCommonPrefix:
+    """Test common_prefix against os.path.commonprefix."""
+
+    def test_common_prefix_partial_component(self) -> None:
+        """Test that the prefix can stop in the middle of a component."""
+        paths = ("/usr/lib", "/usr/local")
+        assert pyopath.PurePosixPath.common_prefix(paths) == "/usr/l"
+        assert str(pyopath.PurePosixPath.common_path(paths)) == "/usr"
+
+    def test_common_prefix_parity(self) -> None:
+        """Test common_prefix matches os.path.commonprefix."""
+        cases = pc.Seq(
+            (
+                ("/usr/lib", "/usr/local", "/usr/libexec"),
+                ("abc", "abd"),
+                ("a", "b"),
+                ("/a/b", "/a/b"),
+                ("café/x", "café/y"),
+            )
+        )
+
+        def _check(paths: tuple[str, ...]) -> None:
+            expected = os.path.commonprefix(list(paths))
+            assert pyopath.PurePosixPath.common_prefix(paths) == expected
+
+        cases.iter().for_each(_check)
+
+    def test_common_prefix_edge_cases(self) -> None:
+        """Test empty input, path objects and Windows separators."""
+        assert pyopath.PurePosixPath.common_prefix([]) == ""
+        assert (
+            pyopath.PurePosixPath.common_prefix([pyopath.PurePosixPath("/ab"), "/ac"])
+            == "/a"
+        )
+
+    def test_common_prefix_is_not_normalized(self) -> None:
+        """Test the raw strings are compared, as os.path.commonprefix does."""
+        cases = pc.Seq(
+            (
+                ("/usr/lib", "/usr/local"),
+                ("C:/ab", "C:\\ac"),
+                ("a//b", "a//c"),
+                ("a/./b", "a/./c"),
+            )
+        )
+
+        def _check(paths: tuple[str, ...]) -> None:
+            expected = ntpath.commonprefix(list(paths))
+            assert pyopath.PureWindowsPath.common_prefix(paths) == expected
+
+        cases.iter().for_each(_check)
+        paths = ["/usr/lib", "/usr/local"]
+        assert pyopath.PureWindowsPath.common_prefix(paths) == "/usr/l"
+
+
+class TestPurePathExpandUser:
+    """Test expanduser against os.path.expanduser."""
+
+    def test_expanduser_home(self, monkeypatch: pytest.MonkeyPatch) -> None:
+        """Test that a leading '~' expands to HOME."""
+        monkeypatch.setenv("HOME", "/home/tester")
+        expanded = pyopath.PurePosixPath("~/x").expanduser()
+        assert str(expanded) == posixpath.expanduser("~/x")
+        assert str(expanded) == "/home/tester/x"
+        assert expanded.is_absolute()
+
+    def test_expanduser_unchanged(self) -> None:
+        """Test that paths without a leading '~' form are returned unchanged."""
+        paths = pc.Seq(("a/~/b", "/~", "x~", "."))
+
+        def _check(p: str) -> None:
+            path = pyopath.PurePosixPath(p)
+            assert path.expanduser() is path
+
+        paths.iter().for_each(_check)
+
+
+class TestPurePathIsReserved:
+    """Test detection of reserved Windows names."""
+
+    def test_reserved_windows(self) -> None:
+        """Test device names, with or without extension, in any component."""
+        names = ("CON", "nul", "NUL.txt", "com1", "LPT9.log", "aux .x", "a/PRN/b")
+
+        def _check(name: str) -> None:
+            assert pyopath.PureWindowsPath(name).is_reserved(), name
+
+        pc.Seq(names).iter().for_each(_check)
+        assert pyopath.PureWindowsPath("C:/Users/con").is_reserved()
+        assert pyopath.PureWindowsPath("a.").is_reserved()
+        assert pyopath.PureWindowsPath("a/b*").is_reserved()
+
+    def test_not_reserved_windows(self) -> None:
+        """Test ordinary names that only resemble device names."""
+        names = ("foo", "console", "con_file", "COM0", "COM10", "LPT", ".", "..")
+
+        def _check(name: str) -> None:
+            assert not pyopath.PureWindowsPath(name).is_reserved(), name
+
+        pc.Seq(names).iter().for_each(_check)
+        assert not pyopath.PureWindowsPath("C:/").is_reserved()
+        assert not pyopath.PureWindowsPath(r"\\server\nul").is_reserved()
+
+    def test_reserved_matches_ntpath(self) -> None:
+        """Test is_reserved against ntpath.isreserved."""
+        def _check(name: str) -> None:
+            expected = ntpath.isreserved(name)
+            assert pyopath.PureWindowsPath(name).is_reserved() == expected, name
+
+        pc.Seq(
+            ("CONIN$", "conout$.txt", "COM\xb9", "x\x01", "a ", "a?b", "C:/x/aux")
+        ).iter().for_each(_check)
+
+    def test_reserved_posix(self) -> None:
+        """Test that no name is reserved on POSIX."""
+        for name in ("CON", "NUL.txt", "a.", "com1"):
+            assert not pyopath.PurePosixPath(name).is_reserved()
+
+
+class TestPurePathStr:
+    """Test str() of empty and anchor-only paths."""
+
+    def test_str_empty(self) -> None:
+        """Test that empty paths render as '.'."""
+        assert str(pyopath.PurePosixPath()) == "."
+        assert str(pyopath.PurePosixPath("")) == "."
+        assert str(pyopath.PureWindowsPath()) == "."
+
+    def test_str_anchor_only(self) -> None:
+        """Test that anchor-only paths keep their anchor."""
+        paths = pc.Seq(
+            ("C:\\", "C:", "\\\\server\\share\\", "\\\\server\\share", "\\")
+        )
+
+        def _check(p: str) -> None:
+            assert str(pyopath.PureWindowsPath(p)) == str(pathlib.PureWindowsPath(p))
+
+        paths.iter().for_each(_check)
+        assert str(pyopath.PurePosixPath("/")) == "/"
+        assert str(pyopath.PurePosixPath("//")) == "//"
+        assert str(pyopath.PureWindowsPath("C:\\")) == "C:\\"
+        unc = pyopath.PureWindowsPath("\\\\server\\share")
+        assert str(unc) == "\\\\server\\share\\"
+
+    def test_single_segment_matches_joined(self) -> None:
+        """Test a single already-joined segment parses like the split segments."""
+        cases = pc.Seq(
+            (
+                ("/very/long/absolute/x.txt", ("/", "very", "long/absolute", "x.txt")),
+                ("//a//b/./c/", ("//a//b", ".", "c/")),
+                ("C:/Users/x", ("C:/", "Users", "x")),
+                ("//srv/share/a/b", ("//srv/share", "a", "b")),
+            )
+        )
+
+        def _check(case: tuple[str, tuple[str, ...]]) -> None:
+            single, split = case
+            for ours, std in (
+                (pyopath.PurePosixPath, pathlib.PurePosixPath),
+                (pyopath.PureWindowsPath, pathlib.PureWindowsPath),
+            ):
+                assert str(ours(single)) == str(std(single))
+                assert ours(single).parts == std(single).parts
+                assert ours(single) == ours(*split)
+
+        cases.iter().for_each(_check)
+
+
 class TestPurePathComparison:
     """Test PurePath comparison operations."""
 
@@ -222,20 +880,153 @@ class TestPurePathComparison:
         path_set = {p1, p2}
         assert len(path_set) == 1
 
+    def test_hash_mixed_flavors(self) -> None:
+        """Test same-string paths of both flavors are distinct dict keys."""
+        posix = pyopath.PurePosixPath("a")
+        windows = pyopath.PureWindowsPath("a")
+        assert hash(posix) != hash(windows)
+
+        mapping = {posix: "posix", windows: "windows"}
+        assert len(mapping) == 2
+        assert mapping[pyopath.PurePosixPath("a")] == "posix"
+        assert mapping[pyopath.PureWindowsPath("A")] == "windows"
+
+    def test_windows_equality_only_folds_case(self) -> None:
+        """Test trailing dots and spaces stay significant like in pathlib."""
+        pairs = pc.Seq(
+            (("a.", "a"), ("a ", "a"), ("dir./f", "dir/f"), ("A.TXT", "a.txt"))
+        )
+
+        def _check(pair: tuple[str, str]) -> None:
+            left, right = pair
+            expected = pathlib.PureWindowsPath(left) == pathlib.PureWindowsPath(right)
+            ours = pyopath.PureWindowsPath(left) == pyopath.PureWindowsPath(right)
+            assert ours == expected, pair
+
+        pairs.iter().for_each(_check)
+        assert pyopath.PureWindowsPath("a.") != pyopath.PureWindowsPath("a")
+        assert hash(pyopath.PureWindowsPath("a.")) != hash(pyopath.PureWindowsPath("a"))
+        assert pyopath.PureWindowsPath("A.TXT") == pyopath.PureWindowsPath("a.txt")
+
+
+class TestPurePathCacheFreshness:
+    """Test that derived paths never reuse the caches of their source path."""
+
+    @staticmethod
+    def _warm(p: pyopath.PurePosixPath) -> None:
+        """Fill every lazily computed cache of a path."""
+        _ = (str(p), p.parts, hash(p), p._str_normcase, p._parts_normcase)
+
+    def test_derived_paths_posix(self) -> None:
+        """Test parts, str and hash of paths built by compute methods."""
+        source = pyopath.PurePosixPath("/a/b/c.txt")
+        self._warm(source)
+        derived = (
+            (source.joinpath("d"), "/a/b/c.txt/d"),
+            (source / "d", "/a/b/c.txt/d"),
+            (source.relative_to("/a"), "b/c.txt"),
+            (source.with_name("x.py"), "/a/b/x.py"),
+            (source.with_suffix(".md"), "/a/b/c.md"),
+            (source.with_stem("y"), "/a/b/y.txt"),
+            (source.parent, "/a/b"),
+        )
+
+        def _check(pair: tuple[pyopath.PurePosixPath, str]) -> None:
+            result, expected = pair
+            fresh = pyopath.PurePosixPath(expected)
+            assert str(result) == expected
+            assert result.parts == fresh.parts
+            assert result.name == fresh.name
+            assert hash(result) == hash(fresh)
+            assert result == fresh
+
+        pc.Seq(derived).iter().for_each(_check)
+        assert str(source) == "/a/b/c.txt"
+        assert source.parts == ("/", "a", "b", "c.txt")
+
+    def test_derived_paths_windows(self) -> None:
+        """Test normcase caches of derived Windows paths are recomputed."""
+        source = pyopath.PureWindowsPath("C:/Dir/File.TXT")
+        _ = (hash(source), source._str_normcase, source._parts_normcase)
+        renamed = source.with_name("Other.md")
+
+        assert renamed._str_normcase == "c:\\dir\\other.md"
+        assert renamed._parts_normcase == ["c:", "dir", "other.md"]
+        assert hash(renamed) == hash(pyopath.PureWindowsPath("c:/dir/OTHER.MD"))
+        assert source._str_normcase == "c:\\dir\\file.txt"
+
 
 class TestPurePathFspath:
     """Test os.fspath compatibility."""
 
     def test_fspath(self) -> None:
         """Test __fspath__ method."""
-        import os
-
         # On Windows, PurePath uses backslashes (native separator)
         # So we compare with pathlib's behavior which is platform-dependent
         p = pyopath.PurePath("/home/user/file.txt")
         expected = str(pathlib.PurePath("/home/user/file.txt"))
         assert os.fspath(p) == expected
 
+    def test_pathlike_arguments(self) -> None:
+        """Test that objects implementing __fspath__ are accepted."""
+        assert str(pyopath.PurePosixPath(_FsPath("a"), "sub")) == "a/sub"
+        assert str(pyopath.PurePosixPath("x").joinpath(_FsPath("y"), "z")) == "x/y/z"
+        assert str(pyopath.PurePosixPath(pathlib.PurePosixPath("q"), "z")) == "q/z"
+        assert str(pyopath.PurePosixPath("x") / _FsPath("y")) == "x/y"
+
+    def test_pathlike_returning_bytes_raises(self) -> None:
+        """Test that __fspath__ returning bytes raises TypeError."""
+        with pytest.raises(TypeError):
+            pyopath.PurePosixPath(_FsPath(b"a"))  # type: ignore[arg-type]
+        with pytest.raises(TypeError):
+            pyopath.PurePosixPath("x").joinpath(_FsPath(b"y"))  # type: ignore[arg-type]
+
+    def test_unencodable_str_keeps_its_error(self) -> None:
+        """Test a str with lone surrogates isn't reported as 'not a str'."""
+        for arg in ("a\udcff", _FsPath("a\udcff")):
+            with pytest.raises(UnicodeEncodeError):
+                pyopath.PurePosixPath(arg)
+        with pytest.raises(UnicodeEncodeError):
+            pyopath.PurePosixPath("a") / "b\udcff"
+
+    def test_bytes_arguments(self) -> None:
+        """Test that bytes segments are decoded with os.fsdecode."""
+        p = pyopath.PurePosixPath(b"/tmp/x")
+        assert str(p) == "/tmp/x"
+        assert p == pyopath.PurePosixPath("/tmp/x")
+        assert str(pyopath.PurePosixPath("a", b"b", "c")) == "a/b/c"
+        assert str(pyopath.PurePosixPath("a").joinpath(b"b")) == "a/b"
+        assert str(pyopath.PurePosixPath("a") / b"b") == "a/b"
+        assert str(pyopath.PureWindowsPath(b"C:/x")) == "C:\\x"
+        name = "caf\xe9"
+        assert pyopath.PurePosixPath(os.fsencode(name)).name == name
+        with pytest.raises(ValueError, match="embedded null byte"):
+            pyopath.PurePosixPath(b"a\0b")
+
+    def test_undecodable_bytes_raise(self) -> None:
+        """Test non-UTF-8 bytes raise UnicodeEncodeError instead of a bogus TypeError.
+
+        os.fsdecode turns them into lone surrogates, which paths can't store.
+        """
+        for arg in (b"/tmp/\xff", b"caf\xe9"):
+            with pytest.raises(UnicodeEncodeError, match="surrogates not allowed"):
+                pyopath.PurePosixPath(arg)
+        with pytest.raises(UnicodeEncodeError):
+            pyopath.PurePosixPath("a").joinpath(b"\xff")
+
+    def test_embedded_null_byte_raises(self) -> None:
+        """Test that segments containing NUL are rejected."""
+        with pytest.raises(ValueError, match="embedded null byte"):
+            pyopath.PurePosixPath("a\0b")
+        with pytest.raises(ValueError, match="embedded null byte"):
+            pyopath.PureWindowsPath("C:/", "x\0")
+        with pytest.raises(ValueError, match="embedded null byte"):
+            pyopath.PurePosixPath("a") / "x\0y"
+        with pytest.raises(ValueError, match="embedded null byte"):
+            "x\0y" / pyopath.PurePosixPath("a")
+        with pytest.raises(ValueError, match="embedded null byte"):
+            pyopath.PurePosixPath("a").joinpath(_FsPath("\0"))
+
 
 class TestPurePathCrossPlatformConversion:
     """Test PurePath type conversion between platforms."""
@@ -285,6 +1076,93 @@ class TestPurePathCrossPlatformConversion:
         # Should have forward slashes
         assert str(posix_path) == "//server/share/file.txt"
 
+    def test_to_posix_and_to_windows(self) -> None:
+        """Test the explicit conversion helpers."""
+        win_path = pyopath.PurePosixPath("a/b").to_windows()
+
+        assert isinstance(win_path, pyopath.PureWindowsPath)
+        assert str(win_path) == "a\\b"
+        assert win_path == pyopath.PureWindowsPath("a\\b")
+
+        posix_path = pyopath.PureWindowsPath("C:\\x\\y").to_posix()
+
+        assert isinstance(posix_path, pyopath.PurePosixPath)
+        assert str(posix_path) == "C:/x/y"
+        assert posix_path.to_windows() == pyopath.PureWindowsPath("C:\\x\\y")
+
+        same = pyopath.PurePosixPath("/a/b")
+        assert same.to_posix() == same
+
+
+class TestPurePathPickle:
+    """Test pickling preserves the path flavor."""
+
+    def test_round_trip(self) -> None:
+        """Test pickle and copy round-trips keep the class and the string."""
+        paths = pc.Seq(
+            (
+                pyopath.PurePosixPath("/a/b"),
+                pyopath.PurePosixPath("a//b/"),
+                pyopath.PureWindowsPath("C:/a", "b\\c"),
+                pyopath.PureWindowsPath("//srv/share/a"),
+                pyopath.PurePath(),
+            )
+        )
+
+        def _check(p: pyopath.PurePosixPath | pyopath.PureWindowsPath) -> None:
+            restored = pickle.loads(pickle.dumps(p))
+            assert type(restored) is type(p)
+            assert restored == p
+            assert repr(restored) == repr(p)
+            assert copy.deepcopy(p) == p
+
+        paths.iter().for_each(_check)
+
+    def test_derived_paths_round_trip(self) -> None:
+        """Test paths built from parsed parts rather than raw segments survive."""
+        posix = pyopath.PurePosixPath("/a/b/c.txt")
+        windows = pyopath.PureWindowsPath("C:/x/y/z.md")
+        paths = pc.Seq(
+            (
+                posix.parent,
+                posix.parents[1],
+                posix.with_name("d.txt"),
+                posix.with_suffix(".md"),
+                posix.relative_to("/a"),
+                pyopath.PurePosixPath("/a/../b").normpath(),
+                pyopath.PurePosixPath.from_parts("", "//", ["a"]),
+                pyopath.PurePosixPath.common_path(["/a/b", "/a/c"]),
+                windows.parent,
+                windows.with_stem("w"),
+                windows.relative_to("c:/x"),
+                pyopath.PureWindowsPath.from_parts("\\\\srv\\share", "\\", ["a"]),
+            )
+        )
+
+        def _check(p: pyopath.PurePosixPath | pyopath.PureWindowsPath) -> None:
+            restored = pickle.loads(pickle.dumps(p))
+            assert type(restored) is type(p)
+            assert str(restored) == str(p)
+            assert restored.parts == p.parts
+            assert copy.deepcopy(p) == p
+
+        paths.iter().for_each(_check)
+        restored = pickle.loads(pickle.dumps(posix.parent))
+        assert restored == pyopath.PurePosixPath("/a/b")
+
+    def test_flavor_is_explicit(self) -> None:
+        """Test a pickled path names its flavor rather than the PurePath alias."""
+        data = pickle.dumps(pyopath.PureWindowsPath("C:/x/y"))
+        assert b"PureWindowsPath" in data
+        restored = pickle.loads(data)
+        assert isinstance(restored, pyopath.PureWindowsPath)
+        assert restored.drive == "C:"
+        assert str(restored) == "C:\\x\\y"
+
+        data = pickle.dumps(pyopath.PurePosixPath("C:/x/y"))
+        assert b"PurePosixPath" in data
+        assert str(pickle.loads(data)) == "C:/x/y"
+
 
 class TestPurePathMissingMethods:
     """Test methods that weren't covered in other test classes."""
@@ -297,6 +1175,19 @@ class TestPurePathMissingMethods:
         # Compare repr with pathlib
         assert repr(p) == repr(path_lib)
 
+    def test_repr_is_normalized(self) -> None:
+        """Test repr is derived from the normalized str, like pathlib."""
+        paths = pc.Seq(
+            ("a//b", "a/./b/", "", "C:\\a\\b", "//srv/share/", "a\\b", "it's")
+        )
+
+        def _check(p: str) -> None:
+            assert repr(pyopath.PurePosixPath(p)) == repr(pathlib.PurePosixPath(p))
+            assert repr(pyopath.PureWindowsPath(p)) == repr(pathlib.PureWindowsPath(p))
+
+        paths.iter().for_each(_check)
+        assert repr(pyopath.PurePosixPath("a//b")) == "PurePosixPath('a/b')"
+
     def test_bytes(self) -> None:
         """Test __bytes__ method."""
         p = pyopath.PurePath("/home/user/file.txt")
@@ -330,8 +1221,83 @@ class TestPurePathMissingMethods:
         p = pyopath.PurePosixPath("relative/path")
 
         # Should raise ValueError for relative paths
-        with pytest.raises(ValueError):
+        with pytest.raises(ValueError, match="relative path"):
             p.as_uri()
+        with pytest.raises(ValueError, match="relative path"):
+            pyopath.PureWindowsPath("C:a").as_uri()
+
+    def test_as_uri_percent_encoding(self) -> None:
+        """Test as_uri percent-encodes reserved and non-ASCII characters."""
+        p = pyopath.PurePosixPath("/my docs/a#b?c%d/é.txt")
+        assert p.as_uri() == "file:///my%20docs/a%23b%3Fc%25d/%C3%A9.txt"
+        assert p.as_uri() == pathlib.PurePosixPath(str(p)).as_uri()
+        assert pyopath.PurePosixPath("/a-b_c.d~e").as_uri() == "file:///a-b_c.d~e"
+
+    def test_as_uri_windows_forms(self) -> None:
+        """Test as_uri on drive, UNC and spaced Windows paths against pathlib."""
+
+        def _check(raw: str) -> None:
+            expected = pathlib.PureWindowsPath(raw).as_uri()
+            assert pyopath.PureWindowsPath(raw).as_uri() == expected
+
+        pc.Seq(
+            (
+                "C:\\Program Files\\x.txt",
+                "c:/a b/c",
+                "C:\\",
+                "\\\\server\\share\\a b.txt",
+                "//server/share/",
+            )
+        ).iter().for_each(_check)
+        p = pyopath.PureWindowsPath("C:\\Program Files\\x.txt")
+        assert p.as_uri() == "file:///C:/Program%20Files/x.txt"
+        unc = pyopath.PureWindowsPath("\\\\server\\share\\a b.txt")
+        assert unc.as_uri() == "file://server/share/a%20b.txt"
+
+    def test_from_uri_posix(self) -> None:
+        """Test from_uri decodes percent-escapes like pathlib."""
+
+        def _check(uri: str) -> None:
+            expected = str(pathlib.PosixPath.from_uri(uri))
+            assert str(pyopath.PurePosixPath.from_uri(uri)) == expected
+
+        pc.Seq(
+            (
+                "file:///etc/hosts",
+                "file:///my%20docs/a%23b.txt",
+                "file:///%C3%A9t%C3%A9",
+                "file://localhost/etc",
+                "file:/etc",
+                "file:///bad%zzescape",
+            )
+        ).iter().for_each(_check)
+        p = pyopath.PurePosixPath.from_uri("file:///my%20docs/file.txt")
+        assert p == pyopath.PurePosixPath("/my docs/file.txt")
+
+    def test_from_uri_windows(self) -> None:
+        """Test from_uri on drive, legacy drive and UNC URIs."""
+        p = pyopath.PureWindowsPath.from_uri("file:///C:/Users/a%20b")
+        assert p == pyopath.PureWindowsPath("C:\\Users\\a b")
+        assert str(pyopath.PureWindowsPath.from_uri("file:///c|/x")) == "c:\\x"
+        unc = pyopath.PureWindowsPath.from_uri("file://///server/share/a")
+        assert str(unc) == "\\\\server\\share\\a"
+        assert pyopath.PureWindowsPath.from_uri(p.as_uri()) == p
+
+    def test_from_uri_invalid(self) -> None:
+        """Test from_uri rejects other schemes and relative URIs."""
+        with pytest.raises(ValueError, match="^URI does not start with 'file:'"):
+            pyopath.PurePosixPath.from_uri("http://example.com/a")
+        with pytest.raises(ValueError, match="not absolute"):
+            pyopath.PureWindowsPath.from_uri("file:/Users")
+
+    def test_from_uri_non_utf8_escape(self) -> None:
+        """Test a percent-encoded non-UTF-8 byte raises UnicodeEncodeError.
+
+        pathlib decodes it to a lone surrogate, which paths can't store.
+        """
+        with pytest.raises(UnicodeEncodeError, match="surrogates not allowed"):
+            pyopath.PurePosixPath.from_uri("file:///%FF")
+        assert str(pyopath.PurePosixPath.from_uri("file:///caf%C3%A9")) == "/caf\xe9"
 
     def test_full_match(self) -> None:
         """Test full_match method."""
@@ -373,3 +1339,179 @@ class TestPurePathMissingMethods:
         # Should join all segments
         expected = str(pathlib.PurePath("/usr/local/bin"))
         assert str(new_p) == expected
+
+
+class TestPurePathErrorParity:
+    """Test errors match the stdlib in type and message."""
+
+    @pytest.mark.parametrize(
+        "exc_type, pyopath_call, stdlib_call",
+        [
+            (
+                IndexError,
+                lambda: pyopath.PurePosixPath("/a/b").parents[2],
+                lambda: pathlib.PurePosixPath("/a/b").parents[2],
+            ),
+            (
+                IndexError,
+                lambda: pyopath.PurePosixPath("/a/b").parents[-3],
+                lambda: pathlib.PurePosixPath("/a/b").parents[-3],
+            ),
+            (
+                IndexError,
+                lambda: pyopath.PurePosixPath("/a/b").parents[100],
+                lambda: pathlib.PurePosixPath("/a/b").parents[100],
+            ),
+            (
+                ValueError,
+                lambda: pyopath.PurePosixPath("/a/b").relative_to("/a/c"),
+                lambda: pathlib.PurePosixPath("/a/b").relative_to("/a/c"),
+            ),
+            (
+                ValueError,
+                lambda: pyopath.PurePosixPath("a/b").relative_to("/a", walk_up=True),
+                lambda: pathlib.PurePosixPath("a/b").relative_to("/a", walk_up=True),
+            ),
+            (
+                ValueError,
+                lambda: pyopath.PurePosixPath("/a/b").relative_to(
+                    "/x/../y", walk_up=True
+                ),
+                lambda: pathlib.PurePosixPath("/a/b").relative_to(
+                    "/x/../y", walk_up=True
+                ),
+            ),
+            (
+                ValueError,
+                lambda: pyopath.PurePosixPath("file").with_suffix("txt"),
+                lambda: pathlib.PurePosixPath("file").with_suffix("txt"),
+            ),
+            (
+                ValueError,
+                lambda: pyopath.PurePosixPath("file").with_suffix("it's"),
+                lambda: pathlib.PurePosixPath("file").with_suffix("it's"),
+            ),
+            (
+                ValueError,
+                lambda: pyopath.PurePosixPath("a").with_suffix("."),
+                lambda: pathlib.PurePosixPath("a").with_suffix("."),
+            ),
+            (
+                ValueError,
+                lambda: pyopath.PurePosixPath("a").with_suffix("./"),
+                lambda: pathlib.PurePosixPath("a").with_suffix("./"),
+            ),
+            (
+                ValueError,
+                lambda: pyopath.PurePosixPath("a").with_suffix(".a/b"),
+                lambda: pathlib.PurePosixPath("a").with_suffix(".a/b"),
+            ),
+            (
+                ValueError,
+                lambda: pyopath.PureWindowsPath("a").with_suffix(".a\\b"),
+                lambda: pathlib.PureWindowsPath("a").with_suffix(".a\\b"),
+            ),
+            (
+                ValueError,
+                lambda: pyopath.PureWindowsPath("a").with_suffix(".a/b"),
+                lambda: pathlib.PureWindowsPath("a").with_suffix(".a/b"),
+            ),
+            (
+                ValueError,
+                lambda: pyopath.PurePosixPath("").with_suffix(".x"),
+                lambda: pathlib.PurePosixPath("").with_suffix(".x"),
+            ),
+            (
+                ValueError,
+                lambda: pyopath.PurePosixPath("..a").with_suffix(""),
+                lambda: pathlib.PurePosixPath("..a").with_suffix(""),
+            ),
+            (
+                ValueError,
+                lambda: pyopath.PurePosixPath("dir/..a").with_suffix(""),
+                lambda: pathlib.PurePosixPath("dir/..a").with_suffix(""),
+            ),
+            (
+                ValueError,
+                lambda: pyopath.PureWindowsPath("dir/..a").with_suffix(""),
+                lambda: pathlib.PureWindowsPath("dir/..a").with_suffix(""),
+            ),
+            (
+                ValueError,
+                lambda: pyopath.PurePosixPath.common_path(["/a", "b"]),
+                lambda: posixpath.commonpath(["/a", "b"]),
+            ),
+            (
+                ValueError,
+                lambda: pyopath.PurePosixPath.common_path(["a", "/a/b"]),
+                lambda: posixpath.commonpath(["a", "/a/b"]),
+            ),
+            (
+                ValueError,
+                lambda: pyopath.PureWindowsPath.common_path(["C:/a", "D:/a"]),
+                lambda: ntpath.commonpath(["C:/a", "D:/a"]),
+            ),
+            (
+                ValueError,
+                lambda: pyopath.PureWindowsPath.common_path(["C:/a", "b"]),
+                lambda: ntpath.commonpath(["C:/a", "b"]),
+            ),
+            (
+                ValueError,
+                lambda: pyopath.PureWindowsPath.common_path(["C:a", "C:/a"]),
+                lambda: ntpath.commonpath(["C:a", "C:/a"]),
+            ),
+            (
+                ValueError,
+                lambda: pyopath.PureWindowsPath.common_path(["/a", "C:/a"]),
+                lambda: ntpath.commonpath(["/a", "C:/a"]),
+            ),
+            (
+                ValueError,
+                lambda: pyopath.PureWindowsPath.common_path(["/a", "b"]),
+                lambda: ntpath.commonpath(["/a", "b"]),
+            ),
+            (
+                ValueError,
+                lambda: pyopath.PureWindowsPath.common_path(["a", "\\b"]),
+                lambda: ntpath.commonpath(["a", "\\b"]),
+            ),
+            (
+                ValueError,
+                lambda: pyopath.PureWindowsPath.common_path(["\\\\srv\\share", "C:/a"]),
+                lambda: ntpath.commonpath(["\\\\srv\\share", "C:/a"]),
+            ),
+            (
+                TypeError,
+                lambda: pyopath.PurePosixPath(_FsPath(b"a")),  # type: ignore[arg-type]
+                lambda: pathlib.PurePosixPath(_FsPath(b"a")),  # type: ignore[arg-type]
+            ),
+            (
+                TypeError,
+                lambda: pyopath.PurePosixPath(3),  # type: ignore[arg-type]
+                lambda: pathlib.PurePosixPath(3),  # type: ignore[arg-type]
+            ),
+            (
+                ValueError,
+                lambda: pyopath.PurePosixPath.from_uri("http://example.com/a"),
+                lambda: pathlib.PosixPath.from_uri("http://example.com/a"),
+            ),
+            (
+                ValueError,
+                lambda: pyopath.PurePosixPath.from_uri("file:relative/a"),
+                lambda: pathlib.PosixPath.from_uri("file:relative/a"),
+            ),
+        ],
+    )
+    def test_error_matches_stdlib(
+        self,
+        exc_type: type[Exception],
+        pyopath_call: Callable[[], object],
+        stdlib_call: Callable[[], object],
+    ) -> None:
+        """Test pyopath raises the same exception and message as the stdlib."""
+        with pytest.raises(exc_type) as pyopath_err:
+            pyopath_call()
+        with pytest.raises(exc_type) as stdlib_err:
+            stdlib_call()
+        assert str(pyopath_err.value) == str(stdlib_err.value)
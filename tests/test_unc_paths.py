@@ -60,6 +60,35 @@ class TestUNCPaths:
         assert p_pyopath.root == p_pathlib.root
         assert p_pyopath.name == p_pathlib.name
 
+    def test_unc_as_posix(self) -> None:
+        """Test as_posix converts every backslash of a UNC path."""
+        p_pyopath = pyopath.PureWindowsPath(r"\\srv\share\a")
+        p_pathlib = StdPureWindowsPath(r"\\srv\share\a")
+
+        assert p_pyopath.as_posix() == "//srv/share/a"
+        assert p_pyopath.as_posix() == p_pathlib.as_posix()
+
+    def test_verbatim_as_posix(self) -> None:
+        """Test as_posix on verbatim and device paths."""
+        for raw in (r"\\?\C:\a\b", r"\\?\UNC\srv\share\a", r"\\.\device\x"):
+            p_pyopath = pyopath.PureWindowsPath(raw)
+            p_pathlib = StdPureWindowsPath(raw)
+
+            assert p_pyopath.as_posix() == p_pathlib.as_posix()
+            assert "\\" not in p_pyopath.as_posix()
+
+    def test_incomplete_unc_drive(self) -> None:
+        """Test leading separators that don't form a complete UNC drive."""
+        for raw in ("///", "\\\\\\", "\\\\\\a", "//server/", "//server", "//a//b"):
+            p_pyopath = pyopath.PureWindowsPath(raw)
+            p_pathlib = StdPureWindowsPath(raw)
+
+            assert str(p_pyopath) == str(p_pathlib)
+            assert p_pyopath.drive == p_pathlib.drive
+            assert p_pyopath.root == p_pathlib.root
+            assert tuple(p_pyopath.parts) == p_pathlib.parts
+        assert str(pyopath.PureWindowsPath("///")) == "\\\\\\"
+
 
 class TestWindowsPathConstruction:
     """Test Windows path construction with multiple args."""
@@ -78,3 +107,88 @@ class TestWindowsPathConstruction:
 
         assert p_pyopath.drive == p_pathlib.drive
         assert p_pyopath.root == p_pathlib.root
+
+    def test_mixed_separators(self) -> None:
+        """Test paths mixing '/' and '\\' normalize like pathlib."""
+        paths = (
+            "C:/Users\\test/file.txt",
+            "//server\\share/dir\\f",
+            "\\\\server/share\\dir/f",
+            "a/b\\c",
+            "C:\\a\\b",
+        )
+        for raw in paths:
+            p_pyopath = pyopath.PureWindowsPath(raw)
+            p_pathlib = StdPureWindowsPath(raw)
+
+            assert str(p_pyopath) == str(p_pathlib)
+            assert p_pyopath.drive == p_pathlib.drive
+            assert tuple(p_pyopath.parts) == p_pathlib.parts
+
+        joined = pyopath.PureWindowsPath("C:/a", "b\\c", "d/e")
+        assert str(joined) == str(StdPureWindowsPath("C:/a", "b\\c", "d/e"))
+
+
+class TestDriveOnlyPaths:
+    """Test Windows paths made of a bare drive letter."""
+
+    def test_lone_drive(self) -> None:
+        """Test that 'C:' is a drive with no root and no name."""
+        p = pyopath.PureWindowsPath("C:")
+
+        assert p.drive == "C:"
+        assert p.root == ""
+        assert p.name == ""
+        assert str(p) == "C:"
+        assert tuple(p.parts) == ("C:",)
+
+    def test_drive_relative_dots(self) -> None:
+        """Test 'C:', 'C:.' and 'C:..' against pathlib."""
+        for raw in ("C:", "C:.", "C:..", "C:./a"):
+            p_pyopath = pyopath.PureWindowsPath(raw)
+            p_pathlib = StdPureWindowsPath(raw)
+
+            assert str(p_pyopath) == str(p_pathlib)
+            assert p_pyopath.drive == p_pathlib.drive
+            assert p_pyopath.root == p_pathlib.root
+            assert p_pyopath.name == p_pathlib.name
+            assert tuple(p_pyopath.parts) == p_pathlib.parts
+
+    def test_forward_slash_drive_is_absolute(self) -> None:
+        """Test that 'C:/' is absolute and equivalent to 'C:\\'."""
+        p = pyopath.PureWindowsPath("C:/")
+
+        assert p.is_absolute()
+        assert str(p) == "C:\\"
+        pairs = (("C:/", "C:\\"), ("C:/a", "C:\\a"), ("C:/a/b", "C:\\a\\b"))
+        for forward, backward in pairs:
+            p_forward = pyopath.PureWindowsPath(forward)
+            p_backward = pyopath.PureWindowsPath(backward)
+
+            assert p_forward == p_backward
+            assert str(p_forward) == str(p_backward)
+            assert p_forward.is_absolute() == p_backward.is_absolute()
+
+    def test_is_absolute_drive_relative(self) -> None:
+        """Test is_absolute on drive-relative and rooted paths against pathlib."""
+        for raw in ("C:", "C:a", "C:/", "C:/a", "/a", "\\a"):
+            p_pyopath = pyopath.PureWindowsPath(raw)
+            p_pathlib = StdPureWindowsPath(raw)
+
+            assert p_pyopath.is_absolute() == p_pathlib.is_absolute()
+
+    def test_unc_is_absolute(self) -> None:
+        """Test is_absolute on complete and incomplete UNC paths."""
+        share = pyopath.PureWindowsPath(r"\\server\share")
+
+        assert share.drive == r"\\server\share"
+        assert share.root == "\\"
+        assert share.is_absolute()
+
+        # An incomplete UNC drive has no root, but ntpath still treats a leading
+        # double separator as absolute
+        server = pyopath.PureWindowsPath(r"\\server")
+
+        assert server.drive == r"\\server"
+        assert server.root == ""
+        assert server.is_absolute() == StdPureWindowsPath(r"\\server").is_absolute()